@@ -0,0 +1,143 @@
+#![feature(rustc_private, test)]
+
+// measure the grouping lints on large tables of long literals
+extern crate clippy_lints;
+extern crate syntax;
+extern crate test;
+
+use clippy_lints::literal_digit_grouping::{DigitInfo, LiteralDigitGrouping};
+use clippy_lints::utils::conf;
+use syntax::ast::Crate;
+use syntax::codemap::FilePathMapping;
+use syntax::parse::{self, ParseSess};
+use test::Bencher;
+
+fn parse_crate(src: String, sess: &ParseSess) -> Crate {
+    match parse::parse_crate_from_source_str("table.rs".to_owned(), src, sess) {
+        Ok(krate) => krate,
+        Err(mut err) => {
+            err.emit();
+            panic!("the table doesn't parse");
+        },
+    }
+}
+
+/// 10,000 ungrouped literals, each written by `element`.
+fn elements<F: Fn(u64) -> String>(element: F) -> String {
+    (0..10_000u64)
+        .map(|i| element(1_000_000_000 + i * 7919))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Runs the grouping lints over `src`, which has `expected` suggestions.
+fn bench_suggestions(b: &mut Bencher, src: String, expected: usize) {
+    let sess = ParseSess::new(FilePathMapping::empty());
+    let krate = parse_crate(src, &sess);
+    let mut pass = LiteralDigitGrouping::new(&conf::read(None).0);
+    b.iter(|| {
+        let suggestions = pass.grouping_suggestions(&krate, sess.codemap());
+        assert_eq!(suggestions.len(), expected);
+    });
+}
+
+#[bench]
+fn bench_table_of_long_literals(b: &mut Bencher) {
+    let src = format!("static TABLE: [u64; 10000] = [{}];", elements(|value| value.to_string()));
+    bench_suggestions(b, src, 10_000);
+}
+
+/// Every literal is an index or a mask, which the pass remembers in the scope
+/// of the function and looks up when checking the literal.
+#[bench]
+fn bench_indices_and_masks(b: &mut Bencher) {
+    let src = format!(
+        "fn masked(t: &[u64]) -> [u64; 10000] {{ [{}] }}",
+        elements(|value| format!("t[{0}] & {0}", value))
+    );
+    bench_suggestions(b, src, 20_000);
+}
+
+/// Short literals like these are most of the literals in a crate. Both the
+/// lint pass and the suggestions skip them by their length, before taking
+/// their snippet, so this should be much faster than
+/// `bench_short_literals`.
+#[bench]
+fn bench_tiny_literals(b: &mut Bencher) {
+    let tiny: Vec<String> = (0..10_000u64).map(|i| (i % 100).to_string()).collect();
+    let src = format!("static TABLE: [u64; 10000] = [{}];", tiny.join(", "));
+    bench_suggestions(b, src, 0);
+}
+
+/// Literals just long enough to be parsed, of which none is linted either.
+#[bench]
+fn bench_short_literals(b: &mut Bencher) {
+    let short: Vec<String> = (0..10_000u64).map(|i| (100 + i % 900).to_string()).collect();
+    let src = format!("static TABLE: [u64; 10000] = [{}];", short.join(", "));
+    bench_suggestions(b, src, 0);
+}
+
+/// The sources of 10,000 long literals of all radixes.
+fn literal_sources() -> Vec<String> {
+    (0..10_000u64)
+        .map(|i| {
+            let value = 1_000_000_000 + i * 7919;
+            match i % 3 {
+                0 => value.to_string(),
+                1 => format!("{:#x}_u64", value),
+                _ => format!("{}.{}e3_f64", value, i),
+            }
+        })
+        .collect()
+}
+
+fn grouping(digit_info: &DigitInfo) -> usize {
+    digit_info.grouping_hint().len()
+}
+
+fn case(digit_info: &DigitInfo) -> usize {
+    digit_info.normalized_hint().len()
+}
+
+fn overflow(digit_info: &DigitInfo) -> usize {
+    digit_info.int_value().map_or(0, |value| value as usize)
+}
+
+/// The analyses of a literal, each standing in for a separate literal pass.
+const ANALYSES: [fn(&DigitInfo) -> usize; 3] = [grouping, case, overflow];
+
+/// Parses each literal once per analysis, as separate passes would.
+#[bench]
+fn bench_parse_per_analysis(b: &mut Bencher) {
+    let sources = literal_sources();
+    b.iter(|| {
+        let mut total = 0usize;
+        for src in &sources {
+            let float = src.ends_with("f64");
+            for analysis in &ANALYSES {
+                if let Some(digit_info) = DigitInfo::checked_new(src, float) {
+                    total = total.wrapping_add(analysis(&digit_info));
+                }
+            }
+        }
+        total
+    });
+}
+
+/// Parses each literal once for all analyses, as the combined pass does.
+#[bench]
+fn bench_parse_once(b: &mut Bencher) {
+    let sources = literal_sources();
+    b.iter(|| {
+        let mut total = 0usize;
+        for src in &sources {
+            let float = src.ends_with("f64");
+            if let Some(digit_info) = DigitInfo::checked_new(src, float) {
+                for analysis in &ANALYSES {
+                    total = total.wrapping_add(analysis(&digit_info));
+                }
+            }
+        }
+        total
+    });
+}
@@ -102,6 +102,7 @@ pub mod len_zero;
 pub mod let_if_seq;
 pub mod lifetimes;
 pub mod literal_digit_grouping;
+pub mod literal_usage;
 pub mod loops;
 pub mod map_clone;
 pub mod matches;
@@ -278,6 +279,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     );
     reg.register_late_lint_pass(box escape::Pass{too_large_for_stack: conf.too_large_for_stack});
     reg.register_early_lint_pass(box misc_early::MiscEarly);
+    // registered before the passes below take their values out of `conf`
+    reg.register_early_lint_pass(box literal_digit_grouping::LiteralDigitGrouping::new(&conf));
     reg.register_late_lint_pass(box array_indexing::ArrayIndexing);
     reg.register_late_lint_pass(box panic::Pass);
     reg.register_late_lint_pass(box strings::StringLitAsBytes);
@@ -321,7 +324,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box large_enum_variant::LargeEnumVariant::new(conf.enum_variant_size_threshold));
     reg.register_late_lint_pass(box should_assert_eq::ShouldAssertEq);
     reg.register_late_lint_pass(box needless_pass_by_value::NeedlessPassByValue);
-    reg.register_early_lint_pass(box literal_digit_grouping::LiteralDigitGrouping);
+    reg.register_late_lint_pass(box literal_usage::LiteralUsage::new(conf.safety_critical, conf.max_sleep_secs));
     reg.register_late_lint_pass(box use_self::UseSelf);
     reg.register_late_lint_pass(box bytecount::ByteCount);
     reg.register_late_lint_pass(box infinite_iter::Pass);
@@ -342,6 +345,29 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         if_not_else::IF_NOT_ELSE,
         infinite_iter::MAYBE_INFINITE_ITER,
         items_after_statements::ITEMS_AFTER_STATEMENTS,
+        literal_digit_grouping::DATE_LIKE_LITERAL,
+        literal_digit_grouping::IDENTIFIER_LIKE_LITERAL,
+        literal_digit_grouping::IMPLICIT_FLOAT_LITERAL,
+        literal_digit_grouping::MISLEADING_FLOAT_PRECISION,
+        literal_digit_grouping::NEGATIVE_ZERO_LITERAL,
+        literal_digit_grouping::PADDED_FLOAT_LITERAL,
+        literal_digit_grouping::PREFER_SHIFT_FOR_POWER_OF_TWO,
+        literal_digit_grouping::REPEATING_DECIMAL_LITERAL,
+        literal_digit_grouping::SUFFIX_ON_BINARY_LITERAL,
+        literal_digit_grouping::TIME_SEGMENT_LITERAL,
+        literal_digit_grouping::UNNECESSARY_RADIX_PREFIX,
+        literal_digit_grouping::UPPERCASE_EXPONENT_MARKER,
+        literal_digit_grouping::VERBOSE_ZERO_LITERAL,
+        literal_usage::ASCII_AS_INTEGER_LITERAL,
+        literal_usage::BOOL_LIKE_ENUM_FROM_LITERAL,
+        literal_usage::EXIT_CODE_LITERAL,
+        literal_usage::INTEGER_BOUND_LITERAL,
+        literal_usage::INTEGER_DIVISION_TO_FLOAT,
+        literal_usage::LONG_SLEEP_LITERAL,
+        literal_usage::PERCENTAGE_LITERAL,
+        literal_usage::REDUNDANT_OPERAND_SUFFIX,
+        literal_usage::REPEATED_TIME_CONVERSION,
+        literal_usage::SUSPICIOUS_DURATION_LITERAL,
         matches::SINGLE_MATCH_ELSE,
         mem_forget::MEM_FORGET,
         methods::FILTER_MAP,
@@ -378,6 +404,37 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         utils::internal_lints::LINT_WITHOUT_LINT_PASS,
     ]);
 
+    // Only the grouping lints are categorized so far.
+    reg.register_lint_group("clippy_style", vec![
+        literal_digit_grouping::INCONSISTENT_DIGIT_GROUPING,
+        literal_digit_grouping::LARGE_DIGIT_GROUPS,
+        literal_digit_grouping::SEPARATOR_BEFORE_DECIMAL_POINT,
+        literal_digit_grouping::UNREADABLE_LITERAL,
+    ]);
+
+    // The lints of `literal_digit_grouping`, which are all about how a literal is written.
+    reg.register_lint_group("clippy_literal_representation", vec![
+        literal_digit_grouping::DATE_LIKE_LITERAL,
+        literal_digit_grouping::IDENTIFIER_LIKE_LITERAL,
+        literal_digit_grouping::IMPLICIT_FLOAT_LITERAL,
+        literal_digit_grouping::INCONSISTENT_DIGIT_GROUPING,
+        literal_digit_grouping::LARGE_DIGIT_GROUPS,
+        literal_digit_grouping::MISLEADING_FLOAT_PRECISION,
+        literal_digit_grouping::NEGATIVE_ZERO_LITERAL,
+        literal_digit_grouping::PADDED_FLOAT_LITERAL,
+        literal_digit_grouping::PORTABLE_LITERAL,
+        literal_digit_grouping::PREFER_SHIFT_FOR_POWER_OF_TWO,
+        literal_digit_grouping::REDUNDANT_EXPONENT,
+        literal_digit_grouping::REPEATING_DECIMAL_LITERAL,
+        literal_digit_grouping::SEPARATOR_BEFORE_DECIMAL_POINT,
+        literal_digit_grouping::SUFFIX_ON_BINARY_LITERAL,
+        literal_digit_grouping::TIME_SEGMENT_LITERAL,
+        literal_digit_grouping::UNNECESSARY_RADIX_PREFIX,
+        literal_digit_grouping::UNREADABLE_LITERAL,
+        literal_digit_grouping::UPPERCASE_EXPONENT_MARKER,
+        literal_digit_grouping::VERBOSE_ZERO_LITERAL,
+    ]);
+
     reg.register_lint_group("clippy", vec![
         approx_const::APPROX_CONSTANT,
         array_indexing::OUT_OF_BOUNDS_INDEXING,
@@ -435,7 +492,17 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         lifetimes::UNUSED_LIFETIMES,
         literal_digit_grouping::INCONSISTENT_DIGIT_GROUPING,
         literal_digit_grouping::LARGE_DIGIT_GROUPS,
+        literal_digit_grouping::PORTABLE_LITERAL,
+        literal_digit_grouping::REDUNDANT_EXPONENT,
+        literal_digit_grouping::SEPARATOR_BEFORE_DECIMAL_POINT,
         literal_digit_grouping::UNREADABLE_LITERAL,
+        literal_usage::COMMA_DECIMAL_SEPARATOR,
+        literal_usage::DISCRIMINANT_OVERFLOWS_REPR,
+        literal_usage::INEXACT_FLOAT_INTEGER,
+        literal_usage::NARROW_LITERAL_SUFFIX,
+        literal_usage::OVERFLOWING_GROUPED_LITERAL,
+        literal_usage::SHIFT_EXCEEDS_WIDTH,
+        literal_usage::WIDENED_F32_LITERAL,
         loops::EMPTY_LOOP,
         loops::EXPLICIT_COUNTER_LOOP,
         loops::EXPLICIT_INTO_ITER_LOOP,
@@ -548,6 +615,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         unused_io_amount::UNUSED_IO_AMOUNT,
         unused_label::UNUSED_LABEL,
         vec::USELESS_VEC,
+        zero_div_zero::NONZERO_DIVIDED_BY_ZERO,
         zero_div_zero::ZERO_DIVIDED_BY_ZERO,
     ]);
 }
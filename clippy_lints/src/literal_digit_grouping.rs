@@ -2,9 +2,18 @@
 //! floating-point literal expressions.
 
 use rustc::lint::*;
+use rustc::session::Session;
+use std::cmp;
+use std::collections::HashSet;
+use std::env;
 use syntax::ast::*;
+use syntax::codemap::CodeMap;
+use syntax::ptr::P;
+use syntax::visit::{walk_crate, walk_expr, walk_fn, walk_item, FnKind, Visitor};
 use syntax_pos;
-use utils::{in_external_macro, snippet_opt, span_help_and_lint};
+use utils::conf::{Conf, LineRange, SuggestionStyle};
+use utils::{in_external_macro, in_macro, multispan_sugg, snippet_opt, span_help_and_lint, span_lint_and_sugg,
+            span_lint_and_then};
 
 /// **What it does:** Warns if a long integral or floating-point constant does
 /// not contain underscores.
@@ -25,7 +34,12 @@ declare_lint! {
 }
 
 /// **What it does:** Warns if an integral or floating-point constant is
-/// grouped inconsistently with underscores.
+/// grouped inconsistently with underscores, or if only one endpoint of a range
+/// is grouped, like in `1000..1_000_000`, or only one of two operands of
+/// similar magnitude, like in `1_000 / 1000`, or only some elements of an
+/// array, like in `[1_000, 2000]`. Decimal and hexadecimal literals longer
+/// than a group with every digit in a group of its own, like `1_2_3_4_5`, are
+/// linted too.
 ///
 /// **Why is this bad?** Readers may incorrectly interpret inconsistently
 /// grouped digits.
@@ -62,8 +76,334 @@ declare_lint! {
     "grouping digits into groups that are too large"
 }
 
-#[derive(Debug)]
-enum Radix {
+/// **What it does:** Warns if a floating-point constant has an underscore
+/// right before the decimal point, e.g. `1_000_.5`.
+///
+/// **Why is this bad?** The underscore doesn't separate two groups of digits
+/// and looks like a typo.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// 1_000_.5
+/// ```
+declare_lint! {
+    pub SEPARATOR_BEFORE_DECIMAL_POINT,
+    Warn,
+    "floating-point literals with an underscore right before the decimal point"
+}
+
+/// **What it does:** Checks for negated floating-point zero literals, i.e.
+/// `-0.0`.
+///
+/// **Why is this bad?** `-0.0` compares equal to `0.0`, but its sign still
+/// shows, e.g. in `1.0 / -0.0` or `is_sign_negative`. More often than not the
+/// sign is a leftover rather than intended.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// let x = -0.0;
+/// ```
+declare_lint! {
+    pub NEGATIVE_ZERO_LITERAL,
+    Allow,
+    "negated floating-point zero literals (`-0.0`)"
+}
+
+/// **What it does:** Checks for floating-point literals with an exponent of
+/// zero, e.g. `1.5e0`.
+///
+/// **Why is this bad?** The exponent doesn't change the value and only makes
+/// the literal harder to read.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// let x = 2.5e0;
+/// ```
+declare_lint! {
+    pub REDUNDANT_EXPONENT,
+    Warn,
+    "floating-point literals with an exponent of zero, e.g. `1.5e0`"
+}
+
+/// **What it does:** Checks for floating-point literals with leading zeros in
+/// the integral part or trailing zeros in the fractional part, e.g.
+/// `0100.5000`.
+///
+/// **Why is this bad?** The zeros don't change the value, but make it harder
+/// to read. Leading zeros may also suggest an octal value to C programmers.
+///
+/// **Known problems:** Trailing zeros are sometimes written on purpose to
+/// show the precision of a value. If the exponent of the literal is zero or
+/// has an uppercase marker, it is reported by `redundant_exponent` or
+/// `uppercase_exponent_marker` instead, with a single suggestion for both.
+///
+/// **Example:**
+///
+/// ```rust
+/// let x = 0100.5000;
+/// ```
+declare_lint! {
+    pub PADDED_FLOAT_LITERAL,
+    Allow,
+    "floating-point literals with superfluous leading or trailing zeros, e.g. `0100.5000`"
+}
+
+/// **What it does:** Warns if a literal contains underscores as digit
+/// separators. This lint only runs if `portable-literals` is set in the
+/// configuration, which also disables the other grouping lints.
+///
+/// **Why is this bad?** Constants copied between Rust and C or other
+/// languages without digit separators have to be rewritten.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// 1_000_000
+/// ```
+declare_lint! {
+    pub PORTABLE_LITERAL,
+    Warn,
+    "literals with underscores, if `portable-literals` is configured"
+}
+
+/// **What it does:** Checks for floating-point literals with an uppercase
+/// exponent marker, e.g. `1E10`.
+///
+/// **Why is this bad?** The lowercase `e` is the more common style, and an
+/// uppercase `E` is easily mistaken for a digit.
+///
+/// **Known problems:** An exponent of zero is reported by
+/// `redundant_exponent` instead, with a single suggestion for both.
+///
+/// **Example:**
+///
+/// ```rust
+/// let x = 1E10;
+/// ```
+declare_lint! {
+    pub UPPERCASE_EXPONENT_MARKER,
+    Allow,
+    "floating-point literals with an uppercase exponent marker, e.g. `1E10`"
+}
+
+/// **What it does:** Checks for float literals with more fractional digits
+/// than their type can tell apart, e.g. `0.10000000000000001`.
+///
+/// **Why is this bad?** The literal has the same value as the shorter
+/// `0.1`, but suggests a precision that isn't there.
+///
+/// **Known problems:** Literals with an exponent are not checked. Unsuffixed
+/// literals are assumed to be `f64`.
+///
+/// **Example:**
+///
+/// ```rust
+/// let x = 0.10000000000000001;
+/// ```
+declare_lint! {
+    pub MISLEADING_FLOAT_PRECISION,
+    Allow,
+    "float literals with more digits than their type can represent, e.g. `0.10000000000000001`"
+}
+
+/// **What it does:** Checks for zero written with a radix prefix, e.g. `0x0`
+/// or `0b00`.
+///
+/// **Why is this bad?** The prefix doesn't change the value, and a plain `0`
+/// is easier to read.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// let flags = 0x00;
+/// ```
+declare_lint! {
+    pub VERBOSE_ZERO_LITERAL,
+    Allow,
+    "zero written with a radix prefix, e.g. `0x0`"
+}
+
+/// **What it does:** Checks for float literals with a suffix, but without a
+/// decimal point or an exponent, e.g. `5f64`.
+///
+/// **Why is this bad?** Only the suffix tells that the literal is a float,
+/// which is easily overlooked.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// let x = 5f64;
+/// ```
+declare_lint! {
+    pub IMPLICIT_FLOAT_LITERAL,
+    Allow,
+    "float literals with a suffix, but without a decimal point or exponent, e.g. `5f64`"
+}
+
+/// **What it does:** Checks for constants and assignments of a large power of
+/// two written as a decimal literal, e.g. `65536`.
+///
+/// **Why is this bad?** `1 << 16` tells right away which power of two it is,
+/// and that it is one.
+///
+/// **Known problems:** Only decimal literals of at least the configured
+/// threshold are linted, and only when they are the whole initializer of a
+/// `const` or `static`, or the right hand side of an assignment.
+///
+/// **Example:**
+///
+/// ```rust
+/// const BUFFER_SIZE: usize = 65536;
+/// ```
+declare_lint! {
+    pub PREFER_SHIFT_FOR_POWER_OF_TWO,
+    Allow,
+    "large power of two written as a decimal literal instead of a shift, e.g. `65536`"
+}
+
+/// **What it does:** Checks for decimal integer literals grouped by four from
+/// the left, with at least four groups, like an IBAN or a card number, e.g.
+/// `1234_5678_9012_3456`.
+///
+/// **Why is this bad?** Such a value is data, not a number. It isn't used in
+/// arithmetic, its leading zeros matter, and longer ones exceed the range of
+/// any integer type.
+///
+/// **Known problems:** A number may just happen to be grouped like this.
+///
+/// **Example:**
+///
+/// ```rust
+/// let card = 4111_1111_1111_1111_u64;
+/// ```
+declare_lint! {
+    pub IDENTIFIER_LIKE_LITERAL,
+    Allow,
+    "integer literal grouped like an identifier, e.g. `1234_5678_9012_3456`"
+}
+
+/// **What it does:** Checks for type suffixes on binary or hexadecimal
+/// literals that are operands of `&`, `|` or `^` next to an operand that isn't
+/// a literal, e.g. `flags & 0b1010u8`.
+///
+/// **Why is this bad?** Such a literal is a bit pattern whose type follows
+/// from the other operand, so the suffix only clutters the mask.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// let low = flags & 0x0Fu8;
+/// ```
+declare_lint! {
+    pub SUFFIX_ON_BINARY_LITERAL,
+    Allow,
+    "type suffix on a binary or hexadecimal mask literal, e.g. `flags & 0x0Fu8`"
+}
+
+/// **What it does:** Checks for eight-digit decimal integer literals that
+/// look like a date in the `YYYYMMDD` form, but aren't grouped like one, e.g.
+/// `20210101` or `20_210_101`.
+///
+/// **Why is this bad?** Grouped by thousands, the year, month and day are
+/// hard to tell apart. Grouping them as `2021_01_01`, or storing the date as a
+/// string, shows what is meant.
+///
+/// **Known problems:** A number may just happen to look like a date. An
+/// ungrouped literal is also linted by `unreadable_literal`, which suggests
+/// grouping it by thousands. Where this lint is enabled, `2021_01_01` isn't
+/// linted by `inconsistent_digit_grouping`.
+///
+/// **Example:**
+///
+/// ```rust
+/// const RELEASE: u32 = 20210101;
+/// ```
+declare_lint! {
+    pub DATE_LIKE_LITERAL,
+    Allow,
+    "integer literal that looks like a `YYYYMMDD` date, e.g. `20210101`"
+}
+
+/// **What it does:** Checks for decimal integer literals grouped like a time
+/// of day in the `H_MM_SS` form, e.g. `12_34_56`.
+///
+/// **Why is this bad?** The value isn't the duration it looks like: `1_30_00`
+/// is 13,000, not an hour and a half in seconds.
+///
+/// **Known problems:** A number may just happen to be grouped like this.
+///
+/// **Example:**
+///
+/// ```rust
+/// let timeout = 1_30_00;
+/// ```
+declare_lint! {
+    pub TIME_SEGMENT_LITERAL,
+    Allow,
+    "integer literal grouped like a time of day, e.g. `12_34_56`"
+}
+
+/// **What it does:** Checks for a single binary, octal or hexadecimal digit
+/// below ten, e.g. `0x5`.
+///
+/// **Why is this bad?** The value is the same in decimal, and `5` is easier
+/// to read than `0x5`.
+///
+/// **Known problems:** The prefix may tell that the value is a bit pattern.
+/// Array elements and the operands of bitwise operators are therefore not
+/// linted.
+///
+/// **Example:**
+///
+/// ```rust
+/// let retries = 0x5;
+/// ```
+declare_lint! {
+    pub UNNECESSARY_RADIX_PREFIX,
+    Allow,
+    "single digit below ten written with a radix prefix, e.g. `0x5`"
+}
+
+/// **What it does:** Checks for `const` or `static` float literals that are
+/// the decimal expansion of a simple fraction, e.g. `0.3333333333333333`.
+///
+/// **Why is this bad?** `1.0 / 3.0` says which value is meant, and is as
+/// precise as the float type allows. The expansion has to be checked digit by
+/// digit.
+///
+/// **Known problems:** Only fractions between zero and one with a denominator
+/// of 3, 6, 7 or 9 are recognized, with at least six decimal places.
+///
+/// **Example:**
+///
+/// ```rust
+/// const THIRD: f64 = 0.3333333333333333;
+/// ```
+declare_lint! {
+    pub REPEATING_DECIMAL_LITERAL,
+    Allow,
+    "float literal that is the decimal expansion of a simple fraction, e.g. `0.3333333333333333`"
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Radix {
     Binary,
     Octal,
     Decimal,
@@ -71,17 +411,67 @@ enum Radix {
 }
 
 impl Radix {
-    /// Return a reasonable digit group size for this radix.
+    /// Return a reasonable digit group size for this radix, which is at least
+    /// two, as `grouping_hint` splits the digits into chunks of this size.
     pub fn suggest_grouping(&self) -> usize {
         match *self {
             Radix::Binary | Radix::Hexadecimal => 4,
             Radix::Octal | Radix::Decimal => 3,
         }
     }
+
+    /// Return the number of digits above which an ungrouped literal is
+    /// considered unreadable. Binary digits are read a byte at a time, so
+    /// `0b1010_1010` may be written ungrouped. Hexadecimal literals get two
+    /// bytes, and octal and decimal ones get one digit of slack over their
+    /// group of three.
+    pub fn unreadable_threshold(&self) -> usize {
+        match *self {
+            Radix::Binary => 8,
+            Radix::Hexadecimal => self.suggest_grouping(),
+            Radix::Octal | Radix::Decimal => self.suggest_grouping() + 1,
+        }
+    }
+
+    /// Return the base of this radix.
+    pub fn base(&self) -> u32 {
+        match *self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hexadecimal => 16,
+        }
+    }
+
+    /// Checks whether `c` is a digit of this radix. Underscores, decimal
+    /// points and exponents are not digits.
+    pub fn is_valid_digit(&self, c: char) -> bool {
+        c.is_digit(self.base())
+    }
+
+    /// Return the lowercase radix prefix, or `None` for decimal.
+    pub fn prefix(&self) -> Option<&'static str> {
+        match *self {
+            Radix::Binary => Some("0b"),
+            Radix::Octal => Some("0o"),
+            Radix::Decimal => None,
+            Radix::Hexadecimal => Some("0x"),
+        }
+    }
+
+    /// Return a short explanation of the grouping convention for this radix.
+    pub fn grouping_note(&self) -> &'static str {
+        match *self {
+            Radix::Binary => "binary digits are usually grouped by four, i.e. one nibble per group",
+            Radix::Octal => "octal digits are usually grouped by three, counting from the right",
+            Radix::Decimal => "decimal digits are usually grouped by three, counting from the decimal point",
+            Radix::Hexadecimal => "hexadecimal digits are usually grouped by four, i.e. two bytes per group",
+        }
+    }
 }
 
 #[derive(Debug)]
-struct DigitInfo<'a> {
+pub struct DigitInfo<'a> {
     /// Characters of a literal between the radix prefix and type suffix.
     pub digits: &'a str,
     /// Which radix the literal was represented in.
@@ -96,12 +486,13 @@ struct DigitInfo<'a> {
 
 impl<'a> DigitInfo<'a> {
     pub fn new(lit: &'a str, float: bool) -> Self {
-        // Determine delimiter for radix prefix, if present, and radix.
-        let radix = if lit.starts_with("0x") {
+        // Determine delimiter for radix prefix, if present, and radix. The
+        // uppercase prefixes aren't valid Rust, but may come from elsewhere.
+        let radix = if lit.starts_with("0x") || lit.starts_with("0X") {
             Radix::Hexadecimal
-        } else if lit.starts_with("0b") {
+        } else if lit.starts_with("0b") || lit.starts_with("0B") {
             Radix::Binary
-        } else if lit.starts_with("0o") {
+        } else if lit.starts_with("0o") || lit.starts_with("0O") {
             Radix::Octal
         } else {
             Radix::Decimal
@@ -118,6 +509,8 @@ impl<'a> DigitInfo<'a> {
         let mut last_d = '\0';
         for (d_idx, d) in sans_prefix.char_indices() {
             if !float && (d == 'i' || d == 'u') || float && d == 'f' {
+                // `last_d` is `'\0'` at the first character, so `d_idx` is at
+                // least one whenever it follows an underscore.
                 let suffix_start = if last_d == '_' { d_idx - 1 } else { d_idx };
                 let (digits, suffix) = sans_prefix.split_at(suffix_start);
                 return Self {
@@ -141,12 +534,100 @@ impl<'a> DigitInfo<'a> {
         }
     }
 
-    /// Returns digits grouped in a sensible way.
-    fn grouping_hint(&self) -> String {
-        let group_size = self.radix.suggest_grouping();
-        if self.digits.contains('.') {
-            let mut parts = self.digits.split('.');
-            let int_part_hint = parts
+    /// Builds the integral literal of `value` in `radix`, e.g. to suggest a
+    /// literal in a different radix than the one it was written in. The
+    /// digits are written to `buf`, which the result borrows.
+    ///
+    /// ```rust
+    /// # #![feature(i128_type)]
+    /// use clippy_lints::literal_digit_grouping::{DigitInfo, Radix};
+    ///
+    /// let mut buf = String::new();
+    /// let digit_info = DigitInfo::from_value(255, Radix::Hexadecimal, Some("_u8"), &mut buf);
+    /// assert_eq!(digit_info.grouping_hint(), "0xff_u8");
+    /// ```
+    pub fn from_value(value: u128, radix: Radix, suffix: Option<&'a str>, buf: &'a mut String) -> Self {
+        *buf = match radix {
+            Radix::Binary => format!("{:b}", value),
+            Radix::Octal => format!("{:o}", value),
+            Radix::Decimal => format!("{}", value),
+            Radix::Hexadecimal => format!("{:x}", value),
+        };
+        let buf: &'a String = buf;
+        Self {
+            digits: buf,
+            radix: radix,
+            prefix: radix.prefix(),
+            suffix: suffix,
+            float: false,
+        }
+    }
+
+    /// Like `new`, but returns `None` if `lit` doesn't look like a numeric
+    /// literal, e.g. because error recovery produced a span that is off, or
+    /// a prefix without digits like `0xu8`. A float may lack its integral
+    /// part, as in `.5`.
+    pub fn checked_new(lit: &'a str, float: bool) -> Option<Self> {
+        let first_digit = if float && lit.starts_with('.') { &lit[1..] } else { lit };
+        if !first_digit.starts_with(|c: char| c.is_digit(10)) {
+            return None;
+        }
+        let digit_info = Self::new(lit, float);
+        let has_digit = digit_info.digits.chars().any(|c| digit_info.radix.is_valid_digit(c));
+        if has_digit && digit_info.has_valid_digits() {
+            Some(digit_info)
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether the digits only contain digits of the radix,
+    /// underscores and, for floats, a decimal point and an exponent. This is
+    /// not the case if `new` was given anything but a literal, like `-1` or
+    /// `(1)`.
+    pub fn has_valid_digits(&self) -> bool {
+        self.digits.chars().all(|c| {
+            self.radix.is_valid_digit(c) || c == '_'
+                || (self.float && (c == '.' || c == 'e' || c == 'E' || c == '+' || c == '-'))
+        })
+    }
+
+    /// Splits the digits of a float into its mantissa and its exponent,
+    /// including the `e` or `E`. The exponent is empty if there is none.
+    pub fn split_exponent(&self) -> (&'a str, &'a str) {
+        match self.digits.find(|c| self.float && (c == 'e' || c == 'E')) {
+            Some(e_idx) => self.digits.split_at(e_idx),
+            None => (self.digits, ""),
+        }
+    }
+
+    /// Returns digits grouped in a sensible way. The exponent of a float is
+    /// kept as written.
+    pub fn grouping_hint(&self) -> String {
+        self.grouping_hint_by(self.radix.suggest_grouping())
+    }
+
+    /// Returns digits grouped in groups of `group_size` digits, counting from
+    /// the decimal point. The exponent of a float is kept as written, the
+    /// radix prefix is lowercased. A `group_size` of zero removes all
+    /// separators. Digits that aren't valid are returned as written, so the
+    /// hint never suggests to change them.
+    pub fn grouping_hint_by(&self, group_size: usize) -> String {
+        self.grouping_hint_with(group_size, '_')
+    }
+
+    /// Like `grouping_hint_by`, but separates the groups by `separator`, e.g.
+    /// by `,` to display the number rather than write it in Rust.
+    pub fn grouping_hint_with(&self, group_size: usize, separator: char) -> String {
+        if !self.has_valid_digits() {
+            return format!("{}{}{}", self.prefix.unwrap_or(""), self.digits, self.suffix.unwrap_or(""));
+        }
+        let group_size = if group_size == 0 { usize::max_value() } else { group_size };
+        let separator = separator.to_string();
+        let (digits, exponent) = self.split_exponent();
+        if digits.contains('.') {
+            let mut parts = digits.split('.');
+            let mut int_part_hint = parts
                 .next()
                 .expect("split always returns at least one element")
                 .chars()
@@ -157,7 +638,11 @@ impl<'a> DigitInfo<'a> {
                 .map(|chunk| chunk.into_iter().rev().collect())
                 .rev()
                 .collect::<Vec<String>>()
-                .join("_");
+                .join(&separator);
+            // Rust floats need an integral part, unlike `.5` in other languages.
+            if int_part_hint.is_empty() {
+                int_part_hint.push('0');
+            }
             let frac_part_hint = parts
                 .next()
                 .expect("already checked that there is a `.`")
@@ -167,10 +652,10 @@ impl<'a> DigitInfo<'a> {
                 .chunks(group_size)
                 .map(|chunk| chunk.into_iter().collect())
                 .collect::<Vec<String>>()
-                .join("_");
-            format!("{}.{}{}", int_part_hint, frac_part_hint, self.suffix.unwrap_or(""))
+                .join(&separator);
+            format!("{}.{}{}{}", int_part_hint, frac_part_hint, exponent, self.suffix.unwrap_or(""))
         } else {
-            let hint = self.digits
+            let hint = digits
                 .chars()
                 .rev()
                 .filter(|&c| c != '_')
@@ -179,137 +664,1493 @@ impl<'a> DigitInfo<'a> {
                 .map(|chunk| chunk.into_iter().rev().collect())
                 .rev()
                 .collect::<Vec<String>>()
-                .join("_");
-            format!("{}{}{}", self.prefix.unwrap_or(""), hint, self.suffix.unwrap_or(""))
+                .join(&separator);
+            let prefix = self.prefix.map_or_else(String::new, str::to_lowercase);
+            format!("{}{}{}{}", prefix, hint, exponent, self.suffix.unwrap_or(""))
+        }
+    }
+
+    /// Returns the value of an integral literal, or `None` for floats.
+    pub fn int_value(&self) -> Option<u128> {
+        if self.float {
+            return None;
         }
+        u128::from_str_radix(&self.digits.replace('_', ""), self.radix.base()).ok()
+    }
+
+    /// Returns true for hexadecimal literals with both lower- and uppercase
+    /// letter digits.
+    pub fn has_mixed_case(&self) -> bool {
+        if let Radix::Hexadecimal = self.radix {
+            self.digits.contains(|c| 'a' <= c && c <= 'f') && self.digits.contains(|c| 'A' <= c && c <= 'F')
+        } else {
+            false
+        }
+    }
+
+    /// Returns the grouping hint with the letter digits of mixed-case
+    /// hexadecimal literals uppercased, so that a single suggestion fixes
+    /// both.
+    pub fn normalized_hint(&self) -> String {
+        if self.has_mixed_case() && self.has_valid_digits() {
+            let digits = self.digits.to_uppercase();
+            DigitInfo {
+                digits: &digits,
+                ..*self
+            }.grouping_hint()
+        } else {
+            self.grouping_hint()
+        }
+    }
+}
+
+/// Checks whether `hint` is a literal written like the grouping suggestions
+/// are: valid Rust, with single underscores between the digits of the
+/// mantissa only. The exponent of a float is not checked, as the suggestions
+/// keep it as written.
+///
+/// ```rust
+/// # use clippy_lints::literal_digit_grouping::is_well_formed_hint;
+/// assert!(is_well_formed_hint("0xDEAD_BEEF_u32", false));
+/// assert!(!is_well_formed_hint("1__000", false));
+/// assert!(!is_well_formed_hint(".5", true));
+/// ```
+pub fn is_well_formed_hint(hint: &str, float: bool) -> bool {
+    if !hint.starts_with(|c: char| c.is_digit(10)) {
+        return false;
+    }
+    let digit_info = match DigitInfo::checked_new(hint, float) {
+        Some(digit_info) => digit_info,
+        None => return false,
+    };
+    let mantissa = digit_info.split_exponent().0;
+    let separators_between_digits = !mantissa.starts_with('_') && !mantissa.ends_with('_')
+        && !mantissa.contains("__") && !mantissa.contains("_.") && !mantissa.contains("._");
+    let has_value = float || digit_info.int_value().is_some();
+    !mantissa.is_empty() && separators_between_digits && has_value
+}
+
+/// Characters that editors put in place of an underscore, or that are used
+/// to group digits elsewhere, like the apostrophe in `1’000`.
+const UNICODE_SEPARATORS: [char; 8] = [
+    '\u{a0}',   // no-break space
+    '\u{2009}', // thin space
+    '\u{202f}', // narrow no-break space
+    '\u{2019}', // right single quotation mark
+    '\u{2bc}',  // modifier letter apostrophe
+    '\u{2017}', // double low line
+    '\u{ff3f}', // fullwidth low line
+    '\u{2e0f}', // paragraphos
+];
+
+/// Returns `src` with the Unicode look-alikes of separators between two
+/// digits replaced by underscores, or `None` if there are none. The lexer
+/// rejects such literals, so this is only useful for source that is not lexed yet.
+///
+/// ```rust
+/// use clippy_lints::literal_digit_grouping::ascii_separators;
+///
+/// assert_eq!(ascii_separators("1\u{2019}000"), Some("1_000".to_owned()));
+/// assert_eq!(ascii_separators("1_000"), None);
+/// ```
+pub fn ascii_separators(src: &str) -> Option<String> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut replaced = false;
+    let ascii: String = chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            let between_digits =
+                i > 0 && chars[i - 1].is_digit(16) && chars.get(i + 1).map_or(false, |c| c.is_digit(16));
+            if between_digits && UNICODE_SEPARATORS.contains(&c) {
+                replaced = true;
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    if replaced {
+        Some(ascii)
+    } else {
+        None
     }
 }
 
+/// Returns the grouped form of the literal `src` if it differs from `src`,
+/// e.g. for editor integrations that have no lint context. Unicode look-alikes
+/// of separators are replaced first.
+///
+/// ```rust
+/// use clippy_lints::literal_digit_grouping::suggest_grouping_for;
+///
+/// assert_eq!(suggest_grouping_for("61864918973511"), Some("61_864_918_973_511".to_owned()));
+/// assert_eq!(suggest_grouping_for("1_000"), None);
+/// ```
+pub fn suggest_grouping_for(src: &str) -> Option<String> {
+    if let Some(ascii) = ascii_separators(src) {
+        return Some(suggest_grouping_for(&ascii).unwrap_or(ascii));
+    }
+    // Integer suffixes like `usize` contain an `e`, so only the digits before
+    // one tell whether the literal is a float.
+    let int_info = DigitInfo::new(src, false);
+    let float = int_info.prefix.is_none() && int_info.digits.contains(|c| c == '.' || c == 'e' || c == 'E' || c == 'f');
+    DigitInfo::checked_new(src, float)
+        .map(|digit_info| digit_info.grouping_hint())
+        .and_then(|hint| if hint == src { None } else { Some(hint) })
+}
+
+/// What to note about a literal besides its grouping, and how to word the
+/// suggestion.
+struct Notes {
+    /// Note the decimal value of binary and hexadecimal literals.
+    show_decimal_value: bool,
+    /// The literal is used as an index.
+    index: bool,
+    /// Whether the suggestion has a lead-in.
+    suggestion_style: SuggestionStyle,
+}
+
 enum WarningType {
     UnreadableLiteral,
     InconsistentDigitGrouping,
     LargeDigitGroups,
+    SmallDigitGroups,
+    SeparatorBeforePoint,
 }
 
 
 impl WarningType {
-    pub fn display(&self, grouping_hint: &str, cx: &EarlyContext, span: &syntax_pos::Span) {
-        match *self {
-            WarningType::UnreadableLiteral => span_help_and_lint(
-                cx,
+    pub fn display(&self, digit_info: &DigitInfo, notes: &Notes, cx: &EarlyContext, span: &syntax_pos::Span) {
+        // The labels of the suggestions are kept stable for tools offering them as code actions.
+        let (lint, msg, label) = match *self {
+            WarningType::UnreadableLiteral => (
                 UNREADABLE_LITERAL,
-                *span,
                 "long literal lacking separators",
-                &format!("consider: {}", grouping_hint),
+                "group digits with underscores",
             ),
-            WarningType::LargeDigitGroups => span_help_and_lint(
-                cx,
+            WarningType::LargeDigitGroups => (
                 LARGE_DIGIT_GROUPS,
-                *span,
                 "digit groups should be smaller",
-                &format!("consider: {}", grouping_hint),
+                "split digits into smaller groups",
             ),
-            WarningType::InconsistentDigitGrouping => span_help_and_lint(
-                cx,
+            WarningType::InconsistentDigitGrouping => (
                 INCONSISTENT_DIGIT_GROUPING,
-                *span,
                 "digits grouped inconsistently by underscores",
-                &format!("consider: {}", grouping_hint),
+                "regroup digits consistently",
+            ),
+            WarningType::SmallDigitGroups => (
+                INCONSISTENT_DIGIT_GROUPING,
+                "digits grouped one by one",
+                "merge digits into larger groups",
+            ),
+            WarningType::SeparatorBeforePoint => (
+                SEPARATOR_BEFORE_DECIMAL_POINT,
+                "digit separator right before the decimal point",
+                "remove the separator before the decimal point",
             ),
         };
+        let hint = digit_info.normalized_hint();
+        debug_assert!(is_well_formed_hint(&hint, digit_info.float), "malformed suggestion `{}`", hint);
+        span_lint_and_then(cx, lint, *span, msg, |db| {
+            match notes.suggestion_style {
+                // The bare replacement can't be a suggestion, which always has a message.
+                SuggestionStyle::Terse => {
+                    db.help(&hint);
+                },
+                SuggestionStyle::Verbose => if is_writable_span(cx, *span) {
+                    multispan_sugg(db, label.to_owned(), vec![(*span, hint)]);
+                } else {
+                    db.help(&format!("consider: {}", hint));
+                },
+            }
+            db.note(digit_info.radix.grouping_note());
+            // Grouped by three, an octal literal looks a lot like a decimal one.
+            if let (Radix::Octal, Some(value)) = (digit_info.radix, digit_info.int_value()) {
+                db.note(&format!("the `0o` prefix makes this an octal number, which is {} in decimal", value));
+            }
+            if notes.index {
+                db.note("a misread index is easily out of bounds");
+            }
+            if notes.show_decimal_value {
+                match (digit_info.radix, digit_info.int_value()) {
+                    (Radix::Binary, Some(value)) | (Radix::Hexadecimal, Some(value)) => {
+                        db.note(&format!("the value is {} in decimal", value));
+                    },
+                    _ => (),
+                }
+            }
+            if digit_info.has_mixed_case() {
+                db.note("the suggestion also fixes the casing reported by `mixed_case_hex_literals`");
+            }
+        });
     }
 }
 
-#[derive(Copy, Clone)]
-pub struct LiteralDigitGrouping;
+/// Returns true if a suggestion for `span` can be applied to the source file,
+/// i.e. the span comes from a real file and was written by the user rather
+/// than produced by a macro.
+fn is_writable_span(cx: &EarlyContext, span: syntax_pos::Span) -> bool {
+    (!in_macro(span) || is_macro_argument(span)) && cx.sess().codemap().lookup_char_pos(span.lo()).file.is_real_file()
+}
+
+/// Returns true if `span` comes from a macro expansion, but its code was
+/// written by the user as an argument of the outermost macro invocation, like
+/// the `1000` in `println!("{}", 1000)`.
+fn is_macro_argument(span: syntax_pos::Span) -> bool {
+    let mut ctxt = span.ctxt();
+    let mut call_site = None;
+    while let Some(info) = ctxt.outer().expn_info() {
+        ctxt = info.call_site.ctxt();
+        call_site = Some(info.call_site);
+    }
+    call_site.map_or(false, |call_site| {
+        call_site.lo() <= span.lo() && span.hi() <= call_site.hi()
+    })
+}
+
+/// When a lint of this pass was added and which groups it is in, for tools
+/// that present the lints.
+#[derive(Debug, Clone, Copy)]
+pub struct LintMetadata {
+    /// The name of the lint, as used in attributes.
+    pub name: &'static str,
+    /// The Clippy version the lint was added in.
+    pub version: &'static str,
+    /// The lint groups the lint is in, besides `clippy` or `clippy_pedantic`.
+    pub groups: &'static [&'static str],
+}
+
+/// The metadata of the lints of this pass, generated by `util/update_lints.py`
+/// from their declarations and group registrations.
+// begin lint metadata
+pub static LINT_METADATA: [LintMetadata; 19] = [
+    LintMetadata {
+        name: "unreadable_literal",
+        version: "0.0.148",
+        groups: &["clippy_literal_representation", "clippy_style"],
+    },
+    LintMetadata {
+        name: "inconsistent_digit_grouping",
+        version: "0.0.148",
+        groups: &["clippy_literal_representation", "clippy_style"],
+    },
+    LintMetadata {
+        name: "large_digit_groups",
+        version: "0.0.148",
+        groups: &["clippy_literal_representation", "clippy_style"],
+    },
+    LintMetadata {
+        name: "separator_before_decimal_point",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation", "clippy_style"],
+    },
+    LintMetadata {
+        name: "negative_zero_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "redundant_exponent",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "padded_float_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "portable_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "uppercase_exponent_marker",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "misleading_float_precision",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "verbose_zero_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "implicit_float_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "prefer_shift_for_power_of_two",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "identifier_like_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "suffix_on_binary_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "date_like_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "time_segment_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "unnecessary_radix_prefix",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+    LintMetadata {
+        name: "repeating_decimal_literal",
+        version: "0.0.157",
+        groups: &["clippy_literal_representation"],
+    },
+];
+// end lint metadata
+
+/// The length of the shortest literal the lints of this pass can fire on. It
+/// is `11_`, whose trailing underscore makes it grouped inconsistently. No
+/// other check fires on a shorter literal either.
+const MIN_LINTABLE_LEN: u32 = 3;
+
+/// Returns the snippet of a numeric literal and whether it is a float. The
+/// common short literals like `0` or `42` are skipped before taking the
+/// snippet, as no lint fires on them.
+fn lintable_snippet(lit: &Lit, codemap: &CodeMap) -> Option<(String, bool)> {
+    let float = match lit.node {
+        LitKind::Int(..) => false,
+        LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => true,
+        _ => return None,
+    };
+    if (lit.span.hi() - lit.span.lo()).0 < MIN_LINTABLE_LEN {
+        return None;
+    }
+    codemap.span_to_snippet(lit.span).ok().map(|src| (src, float))
+}
+
+/// The number of lines at the start of a file searched for an `@generated`
+/// comment.
+const GENERATED_MARKER_LINES: usize = 5;
+
+/// Literals already checked while visiting a single item or function.
+#[derive(Default)]
+struct LiteralScope {
+    /// Spans of the checked literals. A set, as a large array may have
+    /// thousands of them.
+    literals: HashSet<syntax_pos::Span>,
+    /// Spans of the literals used as an index, like the `1` in `a[1]`.
+    index_literals: HashSet<syntax_pos::Span>,
+    /// Spans of the literals used as a mask with a non-literal operand, like
+    /// the `0xFF` in `x & 0xFF`.
+    mask_literals: HashSet<syntax_pos::Span>,
+    /// Spans of the literals that are elements of an array, like the `0x0A`
+    /// in `[0x0A, 0x0D]`.
+    array_literals: HashSet<syntax_pos::Span>,
+}
+
+pub struct LiteralDigitGrouping {
+    /// One scope per item or function currently being visited, innermost last.
+    scopes: Vec<LiteralScope>,
+    /// Digit groups larger than this are linted by `LARGE_DIGIT_GROUPS`.
+    large_digit_group_threshold: u64,
+    /// Lint underscores in literals instead of their grouping.
+    portable_literals: bool,
+    /// Show the decimal value of binary and hexadecimal literals.
+    show_decimal_value: bool,
+    /// How to word the suggestions of the grouping lints.
+    suggestion_style: SuggestionStyle,
+    /// Powers of two this large or larger are linted by `PREFER_SHIFT_FOR_POWER_OF_TWO`.
+    power_of_two_shift_threshold: u64,
+    /// Don't lint literals in generated files.
+    skip_generated_files: bool,
+    /// The start of the file last checked for being generated, and the result.
+    generated_file: Option<(syntax_pos::BytePos, bool)>,
+    /// If not empty, only literals on these lines are linted.
+    lintable_lines: Vec<LineRange>,
+    /// If not zero, ungrouped literals with this many digits are unreadable.
+    require_grouping_threshold: u64,
+    /// The values of integral literals whose grouping isn't checked.
+    ignored_values: HashSet<u128>,
+    /// The level of `DATE_LIKE_LITERAL` in each node with attributes
+    /// currently being visited, innermost last.
+    date_like_levels: Vec<Level>,
+}
+
+/// Collects the grouping suggestions of a crate for `grouping_suggestions`,
+/// pushing and popping the scopes of the pass like the lint hooks do.
+struct SuggestionCollector<'p, 'c> {
+    pass: &'p mut LiteralDigitGrouping,
+    codemap: &'c CodeMap,
+    suggestions: Vec<(syntax_pos::Span, String)>,
+}
+
+impl<'p, 'c, 'a> Visitor<'a> for SuggestionCollector<'p, 'c> {
+    fn visit_item(&mut self, item: &'a Item) {
+        self.pass.scopes.push(LiteralScope::default());
+        walk_item(self, item);
+        self.pass.scopes.pop();
+    }
+
+    fn visit_fn(&mut self, kind: FnKind<'a>, decl: &'a FnDecl, span: syntax_pos::Span, _: NodeId) {
+        self.pass.scopes.push(LiteralScope::default());
+        walk_fn(self, kind, decl, span);
+        self.pass.scopes.pop();
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        self.pass.record_context(expr);
+        if let ExprKind::Lit(ref lit) = expr.node {
+            if self.pass.record(lit.span) {
+                self.pass.push_grouping_suggestion(lit, self.codemap, &mut self.suggestions);
+            }
+        }
+        walk_expr(self, expr);
+    }
+}
 
 impl LintPass for LiteralDigitGrouping {
     fn get_lints(&self) -> LintArray {
-        lint_array!(UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING, LARGE_DIGIT_GROUPS)
+        lint_array!(
+            UNREADABLE_LITERAL,
+            INCONSISTENT_DIGIT_GROUPING,
+            LARGE_DIGIT_GROUPS,
+            NEGATIVE_ZERO_LITERAL,
+            REDUNDANT_EXPONENT,
+            PADDED_FLOAT_LITERAL,
+            PORTABLE_LITERAL,
+            UPPERCASE_EXPONENT_MARKER,
+            MISLEADING_FLOAT_PRECISION,
+            VERBOSE_ZERO_LITERAL,
+            IMPLICIT_FLOAT_LITERAL,
+            SEPARATOR_BEFORE_DECIMAL_POINT,
+            PREFER_SHIFT_FOR_POWER_OF_TWO,
+            IDENTIFIER_LIKE_LITERAL,
+            SUFFIX_ON_BINARY_LITERAL,
+            REPEATING_DECIMAL_LITERAL,
+            DATE_LIKE_LITERAL,
+            TIME_SEGMENT_LITERAL,
+            UNNECESSARY_RADIX_PREFIX
+        )
     }
 }
 
 impl EarlyLintPass for LiteralDigitGrouping {
+    fn enter_lint_attrs(&mut self, cx: &EarlyContext, attrs: &[Attribute]) {
+        let outer = match self.date_like_levels.last() {
+            Some(&level) => level,
+            None => command_line_date_like_level(cx.sess).unwrap_or(DATE_LIKE_LITERAL.default_level),
+        };
+        self.date_like_levels.push(attrs_date_like_level(attrs).unwrap_or(outer));
+    }
+
+    fn exit_lint_attrs(&mut self, _: &EarlyContext, _: &[Attribute]) {
+        let last = self.date_like_levels.pop();
+        debug_assert!(last.is_some());
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        self.scopes.push(LiteralScope::default());
+        if self.should_skip(cx, item.span) {
+            return;
+        }
+        match item.node {
+            ItemKind::Const(_, ref init) | ItemKind::Static(_, _, ref init) => {
+                check_power_of_two(cx, init, self.power_of_two_shift_threshold);
+                check_repeating_decimal(cx, init);
+            },
+            _ => (),
+        }
+    }
+
+    fn check_item_post(&mut self, _: &EarlyContext, _: &Item) {
+        let last = self.scopes.pop();
+        debug_assert!(last.is_some());
+    }
+
+    fn check_fn(&mut self, _: &EarlyContext, _: FnKind, _: &FnDecl, _: syntax_pos::Span, _: NodeId) {
+        self.scopes.push(LiteralScope::default());
+    }
+
+    fn check_fn_post(&mut self, _: &EarlyContext, _: FnKind, _: &FnDecl, _: syntax_pos::Span, _: NodeId) {
+        let last = self.scopes.pop();
+        debug_assert!(last.is_some());
+    }
+
     fn check_expr(&mut self, cx: &EarlyContext, expr: &Expr) {
-        if in_external_macro(cx, expr.span) {
+        if self.should_skip(cx, expr.span) {
             return;
         }
+        self.record_context(expr);
 
-        if let ExprKind::Lit(ref lit) = expr.node {
-            self.check_lit(cx, lit)
+        match expr.node {
+            ExprKind::Lit(ref lit) => if self.record(lit.span) {
+                // The literal is parsed once for all of the checks below.
+                if_let_chain! {[
+                    let Some((src, float)) = lintable_snippet(lit, cx.sess().codemap()),
+                    let Some(digit_info) = DigitInfo::checked_new(&src, float),
+                ], {
+                    self.check_parsed_lit(cx, lit, &src, &digit_info);
+                }}
+            },
+            ExprKind::Range(Some(ref start), Some(ref end), _) => if !self.portable_literals {
+                check_literal_pair(cx, expr, start, end, "range endpoints grouped inconsistently by underscores", false)
+            },
+            ExprKind::Binary(_, ref left, ref right) => if !self.portable_literals {
+                check_literal_pair(cx, expr, left, right, "operands grouped inconsistently by underscores", true)
+            },
+            ExprKind::Array(ref elements) => if !self.portable_literals {
+                check_array_elements(cx, elements)
+            },
+            ExprKind::Assign(_, ref value) => check_power_of_two(cx, value, self.power_of_two_shift_threshold),
+            ExprKind::Unary(UnOp::Neg, ref inner) => if let ExprKind::Lit(ref lit) = inner.node {
+                check_negative_zero(cx, expr, lit)
+            },
+            _ => (),
         }
     }
 }
 
-impl LiteralDigitGrouping {
-    fn check_lit(&self, cx: &EarlyContext, lit: &Lit) {
-        // Lint integral literals.
+fn check_negative_zero(cx: &EarlyContext, expr: &Expr, lit: &Lit) {
+    match lit.node {
+        LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => (),
+        _ => return,
+    }
+    if_let_chain! {[
+        let Some(src) = snippet_opt(cx, lit.span),
+        let Some(digit_info) = DigitInfo::checked_new(&src, true),
+    ], {
+        if digit_info.digits.chars().all(|c| c == '0' || c == '_' || c == '.') {
+            span_lint_and_sugg(
+                cx,
+                NEGATIVE_ZERO_LITERAL,
+                expr.span,
+                "this is negative zero, which compares equal to `0.0`",
+                "remove the sign if it isn't intentional",
+                src.clone(),
+            );
+        }
+    }}
+}
+
+/// Checks a pair of integral literals that are read together, like the
+/// endpoints of a range, of which only one is grouped. With
+/// `similar_magnitude`, only literals whose numbers of digits differ by less
+/// than a group are checked, like in `1_000 / 1000` but not in
+/// `1_000_000 / 1000`.
+fn check_literal_pair(cx: &EarlyContext, expr: &Expr, start: &Expr, end: &Expr, msg: &str, similar_magnitude: bool) {
+    if_let_chain! {[
+        let ExprKind::Lit(ref start_lit) = start.node,
+        let ExprKind::Lit(ref end_lit) = end.node,
+        let LitKind::Int(..) = start_lit.node,
+        let LitKind::Int(..) = end_lit.node,
+        let Some(start_src) = snippet_opt(cx, start_lit.span),
+        let Some(end_src) = snippet_opt(cx, end_lit.span),
+        let Some(start_info) = DigitInfo::checked_new(&start_src, false),
+        let Some(end_info) = DigitInfo::checked_new(&end_src, false),
+        start_info.digits.contains('_') != end_info.digits.contains('_'),
+        let Some(between) = snippet_opt(cx, start.span.between(end.span)),
+    ], {
+        let (start_hint, end_hint) = (start_info.grouping_hint(), end_info.grouping_hint());
+        let (ungrouped, ungrouped_src, ungrouped_hint) = if start_info.digits.contains('_') {
+            (&end_info, &end_src, &end_hint)
+        } else {
+            (&start_info, &start_src, &start_hint)
+        };
+        let digit_count = |digit_info: &DigitInfo| digit_info.digits.chars().filter(|&c| c != '_').count();
+        let (start_len, end_len) = (digit_count(&start_info), digit_count(&end_info));
+        let magnitudes_match = !similar_magnitude
+            || cmp::max(start_len, end_len) - cmp::min(start_len, end_len) < ungrouped.radix.suggest_grouping();
+        // Longer ungrouped literals are already linted as unreadable.
+        if magnitudes_match && ungrouped_hint != ungrouped_src
+            && ungrouped.digits.len() <= ungrouped.radix.unreadable_threshold()
+        {
+            span_lint_and_sugg(
+                cx,
+                INCONSISTENT_DIGIT_GROUPING,
+                expr.span,
+                msg,
+                "consider grouping both",
+                format!("{}{}{}", start_hint, between, end_hint),
+            );
+        }
+    }}
+}
+
+fn check_power_of_two(cx: &EarlyContext, expr: &Expr, threshold: u64) {
+    if_let_chain! {[
+        !in_macro(expr.span),
+        let ExprKind::Lit(ref lit) = expr.node,
+        let LitKind::Int(value, _) = lit.node,
+        value >= u128::from(threshold) && value > 1 && value.is_power_of_two(),
+        let Some(src) = snippet_opt(cx, lit.span),
+        let Some(digit_info) = DigitInfo::checked_new(&src, false),
+        let Radix::Decimal = digit_info.radix,
+    ], {
+        span_lint_and_sugg(
+            cx,
+            PREFER_SHIFT_FOR_POWER_OF_TWO,
+            lit.span,
+            "power of two written as a decimal literal",
+            "consider writing it as a shift",
+            format!("1{} << {}", digit_info.suffix.unwrap_or(""), value.trailing_zeros()),
+        );
+    }}
+}
+
+/// Denominators of the fractions `REPEATING_DECIMAL_LITERAL` recognizes.
+const FRACTION_DENOMINATORS: [u128; 4] = [3, 6, 7, 9];
+
+/// Fewer decimal places than this are not considered an expansion.
+const MIN_EXPANSION_PLACES: usize = 6;
+
+fn check_repeating_decimal(cx: &EarlyContext, expr: &Expr) {
+    match expr.node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => (),
+            _ => return,
+        },
+        _ => return,
+    }
+    if_let_chain! {[
+        !in_macro(expr.span),
+        let Some(src) = snippet_opt(cx, expr.span),
+        let Some(digit_info) = DigitInfo::checked_new(&src, true),
+        digit_info.split_exponent().1.is_empty(),
+    ], {
+        let mantissa = digit_info.digits.replace('_', "");
+        let mut parts = mantissa.splitn(2, '.');
+        if parts.next() != Some("0") {
+            return;
+        }
+        let places = match parts.next() {
+            Some(frac_part) => frac_part,
+            None => return,
+        };
+        if let Some((numerator, denominator)) = simple_fraction(places) {
+            span_lint_and_sugg(
+                cx,
+                REPEATING_DECIMAL_LITERAL,
+                expr.span,
+                &format!("float literal that is the decimal expansion of `{}/{}`", numerator, denominator),
+                "consider writing it as a division",
+                format!("{}.0 / {}.0{}", numerator, denominator, digit_info.suffix.unwrap_or("")),
+            );
+        }
+    }}
+}
+
+/// Finds the fraction between zero and one whose decimal expansion, truncated
+/// or rounded, has the decimal places `places`.
+fn simple_fraction(places: &str) -> Option<(u128, u128)> {
+    // 10^38 still fits into a `u128`, which leaves room for the numerator.
+    if places.len() < MIN_EXPANSION_PLACES || places.len() > 36 {
+        return None;
+    }
+    let scaled = match places.parse::<u128>() {
+        Ok(scaled) => scaled,
+        Err(_) => return None,
+    };
+    let scale = 10u128.pow(places.len() as u32);
+    for &denominator in &FRACTION_DENOMINATORS {
+        for numerator in 1..denominator {
+            let truncated = numerator * scale / denominator;
+            let rounded = (2 * numerator * scale + denominator) / (2 * denominator);
+            // An unreduced fraction is found as the reduced one first, or terminates like 3/6.
+            if (scaled == truncated || scaled == rounded) && gcd(numerator, denominator) == 1 {
+                return Some((numerator, denominator));
+            }
+        }
+    }
+    None
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Years of the dates `DATE_LIKE_LITERAL` recognizes.
+const DATE_YEARS: (u128, u128) = (1900, 2100);
+
+/// Returns the year, month and day of a decimal literal with the value
+/// `value`, if it is a valid date in the `YYYYMMDD` form.
+fn date_parts(value: u128) -> Option<(u128, u128, u128)> {
+    let (year, month, day) = (value / 10_000, value / 100 % 100, value % 100);
+    if year < DATE_YEARS.0 || year >= DATE_YEARS.1 || month < 1 || month > 12 || day < 1 {
+        return None;
+    }
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if day <= days {
+        Some((year, month, day))
+    } else {
+        None
+    }
+}
+
+/// Checks whether `digit_info` is a decimal integer literal grouped like a
+/// date, as in `2021_01_01`.
+fn is_date_grouping(digit_info: &DigitInfo) -> bool {
+    let groups: Vec<usize> = digit_info.digits.split('_').map(str::len).collect();
+    if_let_chain! {[
+        let Radix::Decimal = digit_info.radix,
+        groups == [4, 2, 2],
+        let Some(value) = digit_info.int_value(),
+    ], {
+        return date_parts(value).is_some();
+    }}
+    false
+}
+
+/// The names that set the level of `DATE_LIKE_LITERAL`: its own and those of
+/// its groups.
+fn names_date_like_literal(name: &str) -> bool {
+    match name {
+        "date_like_literal" | "clippy_pedantic" | "clippy_literal_representation" => true,
+        _ => false,
+    }
+}
+
+/// Returns the level the command line sets for `DATE_LIKE_LITERAL`, if any.
+/// The last flag naming it wins.
+fn command_line_date_like_level(sess: &Session) -> Option<Level> {
+    sess.opts
+        .lint_opts
+        .iter()
+        .rev()
+        .find(|&&(ref name, _)| names_date_like_literal(&name.replace('-', "_")))
+        .map(|&(_, level)| level)
+}
+
+/// Returns the level `attrs` set for `DATE_LIKE_LITERAL`, if any. The last
+/// attribute naming it wins.
+fn attrs_date_like_level(attrs: &[Attribute]) -> Option<Level> {
+    let mut level = None;
+    for attr in attrs {
         if_let_chain! {[
-            let LitKind::Int(..) = lit.node,
-            let Some(src) = snippet_opt(cx, lit.span),
-            let Some(firstch) = src.chars().next(),
-            char::to_digit(firstch, 10).is_some()
+            let Some(name) = attr.name(),
+            let Some(attr_level) = Level::from_str(&name.as_str()),
+            let Some(lints) = attr.meta_item_list(),
         ], {
-            let digit_info = DigitInfo::new(&src, false);
-            let _ = Self::do_lint(digit_info.digits).map_err(|warning_type| {
-                warning_type.display(&digit_info.grouping_hint(), cx, &lit.span)
+            let names_it = lints.iter().any(|lint| {
+                if let NestedMetaItemKind::MetaItem(ref mi) = lint.node {
+                    mi.is_word() && names_date_like_literal(&mi.name().as_str())
+                } else {
+                    false
+                }
             });
+            if names_it {
+                level = Some(attr_level);
+            }
         }}
+    }
+    level
+}
 
-        // Lint floating-point literals.
+fn check_date_like(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    if_let_chain! {[
+        let LitKind::Int(..) = lit.node,
+        let Radix::Decimal = digit_info.radix,
+        digit_info.digits.chars().filter(|&c| c != '_').count() == 8,
+        !is_date_grouping(digit_info),
+        let Some(value) = digit_info.int_value(),
+        let Some((year, month, day)) = date_parts(value),
+    ], {
+        span_lint_and_sugg(
+            cx,
+            DATE_LIKE_LITERAL,
+            lit.span,
+            "integer literal that looks like a date",
+            "if it is a date, consider grouping it like one",
+            format!("{}_{:02}_{:02}{}", year, month, day, digit_info.suffix.unwrap_or("")),
+        );
+    }}
+}
+
+fn check_time_segments(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    if_let_chain! {[
+        let LitKind::Int(..) = lit.node,
+        let Radix::Decimal = digit_info.radix,
+    ], {
+        // Hours of one or two digits, then two digits each of minutes and
+        // seconds below 60.
+        let groups: Vec<&str> = digit_info.digits.split('_').collect();
+        if groups.len() != 3 || groups[0].is_empty() || groups[0].len() > 2 || groups[1].len() != 2
+            || groups[2].len() != 2
+        {
+            return;
+        }
+        let parts: Vec<u32> = groups.iter().filter_map(|group| group.parse().ok()).collect();
+        if parts.len() == 3 && parts[1] < 60 && parts[2] < 60 {
+            span_help_and_lint(
+                cx,
+                TIME_SEGMENT_LITERAL,
+                lit.span,
+                "integer literal grouped like a time of day",
+                &format!(
+                    "if this is a duration, consider computing it, e.g. `{} * 3600 + {} * 60 + {}`",
+                    parts[0],
+                    parts[1],
+                    parts[2]
+                ),
+            );
+        }
+    }}
+}
+
+fn check_identifier_like(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    if_let_chain! {[
+        let LitKind::Int(..) = lit.node,
+        let Radix::Decimal = digit_info.radix,
+    ], {
+        // Groups of four, counting from the left, of which the last may be shorter.
+        let groups: Vec<&str> = digit_info.digits.split('_').collect();
+        let (last, init) = groups.split_last().expect("split returns at least one element");
+        if groups.len() >= 4 && init.iter().all(|group| group.len() == 4) && !last.is_empty() && last.len() <= 4 {
+            span_help_and_lint(
+                cx,
+                IDENTIFIER_LIKE_LITERAL,
+                lit.span,
+                "integer literal grouped like an identifier",
+                "if this is an identifier rather than a number, consider storing it as a string",
+            );
+        }
+    }}
+}
+
+/// Returns the span of `mask` if it is a literal whose type follows from the
+/// non-literal `other` operand.
+fn mask_literal(mask: &Expr, other: &Expr) -> Option<syntax_pos::Span> {
+    match (&mask.node, &other.node) {
+        (&ExprKind::Lit(_), &ExprKind::Lit(_)) => None,
+        (&ExprKind::Lit(ref lit), _) => Some(lit.span),
+        _ => None,
+    }
+}
+
+/// Checks a suffixed binary or hexadecimal mask literal whose type follows
+/// from the other operand, which isn't a literal.
+fn check_mask_suffix(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    if_let_chain! {[
+        let LitKind::Int(..) = lit.node,
+        let Some(suffix) = digit_info.suffix,
+    ], {
+        match digit_info.radix {
+            Radix::Binary | Radix::Hexadecimal => (),
+            Radix::Octal | Radix::Decimal => return,
+        }
+        span_lint_and_sugg(
+            cx,
+            SUFFIX_ON_BINARY_LITERAL,
+            lit.span,
+            &format!(
+                "type suffix `{}` on a mask whose type follows from the other operand",
+                suffix.trim_left_matches('_')
+            ),
+            "remove the suffix",
+            format!("{}{}", digit_info.prefix.unwrap_or(""), digit_info.digits.trim_right_matches('_')),
+        );
+    }}
+}
+
+/// Checks the integral literal elements of an array, of which only some are
+/// grouped.
+fn check_array_elements(cx: &EarlyContext, elements: &[P<Expr>]) {
+    let mut literals = Vec::with_capacity(elements.len());
+    for element in elements {
         if_let_chain! {[
-            let LitKind::Float(..) = lit.node,
+            let ExprKind::Lit(ref lit) = element.node,
+            let LitKind::Int(..) = lit.node,
             let Some(src) = snippet_opt(cx, lit.span),
-            let Some(firstch) = src.chars().next(),
-            char::to_digit(firstch, 10).is_some()
         ], {
-            let digit_info = DigitInfo::new(&src, true);
-            // Separate digits into integral and fractional parts.
-            let parts: Vec<&str> = digit_info
-                .digits
-                .split_terminator('.')
-                .collect();
-
-            // Lint integral and fractional parts separately, and then check consistency of digit
-            // groups if both pass.
-            let _ = Self::do_lint(parts[0])
-                .map(|integral_group_size| {
-                    if parts.len() > 1 {
-                        // Lint the fractional part of literal just like integral part, but reversed.
-                        let fractional_part = &parts[1].chars().rev().collect::<String>();
-                        let _ = Self::do_lint(fractional_part)
-                            .map(|fractional_group_size| {
-                                let consistent = Self::parts_consistent(integral_group_size, fractional_group_size, parts[0].len(), parts[1].len());
-                                if !consistent {
-                                    WarningType::InconsistentDigitGrouping.display(&digit_info.grouping_hint(), cx, &lit.span);
-                                }
-                            })
-                            .map_err(|warning_type| warning_type.display(&digit_info.grouping_hint(), cx, &lit.span));
-                    }
-                })
-                .map_err(|warning_type| warning_type.display(&digit_info.grouping_hint(), cx, &lit.span));
+            literals.push((lit.span, src));
+        }}
+    }
+    if !literals.iter().any(|&(_, ref src)| src.contains('_')) {
+        return;
+    }
+    for &(span, ref src) in &literals {
+        if_let_chain! {[
+            !src.contains('_'),
+            let Some(digit_info) = DigitInfo::checked_new(src, false),
+            // Longer ungrouped literals are already linted as unreadable.
+            digit_info.digits.len() <= digit_info.radix.unreadable_threshold(),
+        ], {
+            let hint = digit_info.grouping_hint();
+            if hint != *src {
+                span_lint_and_sugg(
+                    cx,
+                    INCONSISTENT_DIGIT_GROUPING,
+                    span,
+                    "array element grouped inconsistently with the other elements",
+                    "consider grouping it like them",
+                    hint,
+                );
+            }
         }}
     }
+}
+
+fn check_verbose_zero(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    if let LitKind::Int(0, _) = lit.node {
+        if digit_info.prefix.is_some() {
+            span_lint_and_sugg(
+                cx,
+                VERBOSE_ZERO_LITERAL,
+                lit.span,
+                "zero written with a radix prefix",
+                "use a plain zero",
+                format!("0{}", digit_info.suffix.unwrap_or("")),
+            );
+        }
+    }
+}
+
+fn check_radix_prefix(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    if_let_chain! {[
+        let LitKind::Int(value, _) = lit.node,
+        value > 0 && value < 10,
+        digit_info.prefix.is_some(),
+        digit_info.digits.len() == 1,
+    ], {
+        span_lint_and_sugg(
+            cx,
+            UNNECESSARY_RADIX_PREFIX,
+            lit.span,
+            "single digit written with a radix prefix",
+            "use a decimal literal",
+            format!("{}{}", value, digit_info.suffix.unwrap_or("")),
+        );
+    }}
+}
+
+fn check_implicit_float(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    if_let_chain! {[
+        let LitKind::Float(..) = lit.node,
+        !digit_info.digits.contains(|c| c == '.' || c == 'e' || c == 'E'),
+    ], {
+        span_lint_and_sugg(
+            cx,
+            IMPLICIT_FLOAT_LITERAL,
+            lit.span,
+            "float literal without a decimal point",
+            "add a decimal point",
+            format!("{}.0{}", digit_info.digits, digit_info.suffix.unwrap_or("")),
+        );
+    }}
+}
+
+fn check_redundant_exponent(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    match lit.node {
+        LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => (),
+        _ => return,
+    }
+    let (mantissa, exponent) = digit_info.split_exponent();
+    if !exponent.is_empty() {
+        let exponent = exponent[1..].trim_left_matches(|c| c == '+' || c == '-');
+        if exponent.chars().all(|c| c == '0' || c == '_') {
+            // keep the literal a float if the mantissa has no decimal point
+            let point = if mantissa.contains('.') { "" } else { ".0" };
+            span_lint_and_sugg(
+                cx,
+                REDUNDANT_EXPONENT,
+                lit.span,
+                "float literal with an exponent of zero",
+                "remove the exponent",
+                format!("{}{}{}", mantissa, point, digit_info.suffix.unwrap_or("")),
+            );
+        }
+    }
+}
+
+fn check_exponent_marker(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    match lit.node {
+        LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => (),
+        _ => return,
+    }
+    let (mantissa, exponent) = digit_info.split_exponent();
+    if exponent.starts_with('E') {
+        span_lint_and_sugg(
+            cx,
+            UPPERCASE_EXPONENT_MARKER,
+            lit.span,
+            "float literal with an uppercase exponent marker",
+            "use a lowercase `e`",
+            format!("{}e{}{}", mantissa, &exponent[1..], digit_info.suffix.unwrap_or("")),
+        );
+    }
+}
+
+fn check_portable(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    if digit_info.digits.contains('_') {
+        span_lint_and_sugg(
+            cx,
+            PORTABLE_LITERAL,
+            lit.span,
+            "digit separators are not portable to other languages",
+            "remove the underscores",
+            format!(
+                "{}{}{}",
+                digit_info.prefix.unwrap_or(""),
+                digit_info.digits.replace('_', ""),
+                digit_info.suffix.unwrap_or("")
+            ),
+        );
+    }
+}
+
+fn check_padded_float(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    match lit.node {
+        LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => (),
+        _ => return,
+    }
+    let (mantissa, exponent) = digit_info.split_exponent();
+    let mantissa = mantissa.replace('_', "");
+    let mut normalized = strip_float_padding(&mantissa);
+    if normalized != mantissa {
+        normalized.push_str(exponent);
+        let hint = DigitInfo {
+            digits: &normalized,
+            ..*digit_info
+        }.grouping_hint();
+        span_lint_and_sugg(
+            cx,
+            PADDED_FLOAT_LITERAL,
+            lit.span,
+            "float literal with superfluous zeros",
+            "consider",
+            hint,
+        );
+    }
+}
+
+fn check_float_precision(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo) {
+    let single = match lit.node {
+        LitKind::Float(_, FloatTy::F32) => true,
+        LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => false,
+        _ => return,
+    };
+    if !digit_info.split_exponent().1.is_empty() {
+        return;
+    }
+    let mantissa = digit_info.digits.replace('_', "");
+    // `Display` prints the shortest representation that parses to the same value.
+    let shortest = if single {
+        mantissa.parse::<f32>().ok().map(|f| f.to_string())
+    } else {
+        mantissa.parse::<f64>().ok().map(|f| f.to_string())
+    };
+    if_let_chain! {[
+        let Some(mut shortest) = shortest,
+        is_less_precise(&mantissa, &shortest),
+    ], {
+        if !shortest.contains('.') {
+            shortest.push_str(".0");
+        }
+        let hint = DigitInfo {
+            digits: &shortest,
+            ..*digit_info
+        }.grouping_hint();
+        span_lint_and_sugg(
+            cx,
+            MISLEADING_FLOAT_PRECISION,
+            lit.span,
+            &format!("float literal with more digits than `{}` can represent", if single { "f32" } else { "f64" }),
+            "consider using the shortest literal of the same value",
+            hint,
+        );
+    }}
+}
+
+/// Checks whether a file was generated, i.e. is in the `OUT_DIR` of a build
+/// script, or has an `@generated` comment in its first lines.
+fn is_generated_file(filemap: &syntax_pos::FileMap) -> bool {
+    let in_out_dir = env::var("OUT_DIR").ok().map_or(false, |out_dir| filemap.name.starts_with(&out_dir));
+    in_out_dir || filemap.src.as_ref().map_or(false, |src| {
+        src.lines()
+            .take(GENERATED_MARKER_LINES)
+            .any(|line| line.trim_left().starts_with("//") && line.contains("@generated"))
+    })
+}
+
+/// Removes leading zeros from the integral part and trailing zeros from the
+/// fractional part of `mantissa`, which has no underscores. A single zero of a
+/// zero part is kept, and an empty fractional part as in `1.`.
+fn strip_float_padding(mantissa: &str) -> String {
+    let mut parts = mantissa.splitn(2, '.');
+    let int_part = parts.next().expect("splitn returns at least one element");
+    let mut stripped = match int_part.trim_left_matches('0') {
+        "" => "0".to_owned(),
+        int_part => int_part.to_owned(),
+    };
+    if let Some(frac_part) = parts.next() {
+        stripped.push('.');
+        stripped.push_str(match frac_part.trim_right_matches('0') {
+            "" if !frac_part.is_empty() => "0",
+            frac_part => frac_part,
+        });
+    }
+    stripped
+}
+
+/// Checks a float literal that more than one of `REDUNDANT_EXPONENT`,
+/// `UPPERCASE_EXPONENT_MARKER` and `PADDED_FLOAT_LITERAL` apply to. Separate
+/// suggestions would overlap, which `--fix` can't apply, so a single one fixes
+/// everything and is reported under the first of them, in that order. The
+/// others are mentioned in notes. The suggestion is grouped, or has no
+/// separators if `portable` is set, so it also supersedes the grouping lints.
+///
+/// Returns true if the literal was linted, in which case the lints mentioned
+/// must not check it again.
+fn check_float_normalizations(cx: &EarlyContext, lit: &Lit, digit_info: &DigitInfo, portable: bool) -> bool {
+    match lit.node {
+        LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => (),
+        _ => return false,
+    }
+    let (mantissa, exponent) = digit_info.split_exponent();
+    let mantissa = mantissa.replace('_', "");
+    let stripped = strip_float_padding(&mantissa);
+    let zero_exponent = !exponent.is_empty()
+        && exponent[1..]
+            .trim_left_matches(|c| c == '+' || c == '-')
+            .chars()
+            .all(|c| c == '0' || c == '_');
+
+    let mut applied = Vec::new();
+    if zero_exponent {
+        applied.push((REDUNDANT_EXPONENT, "float literal with an exponent of zero", "the exponent is zero"));
+    }
+    if exponent.starts_with('E') {
+        applied.push((
+            UPPERCASE_EXPONENT_MARKER,
+            "float literal with an uppercase exponent marker",
+            "the exponent marker is uppercase",
+        ));
+    }
+    if stripped != mantissa {
+        applied.push((PADDED_FLOAT_LITERAL, "float literal with superfluous zeros", "it has superfluous zeros"));
+    }
+    if applied.len() < 2 {
+        return false;
+    }
 
-    /// Given the sizes of the digit groups of both integral and fractional
-    /// parts, and the length
-    /// of both parts, determine if the digits have been grouped consistently.
-    fn parts_consistent(int_group_size: usize, frac_group_size: usize, int_size: usize, frac_size: usize) -> bool {
-        match (int_group_size, frac_group_size) {
-            // No groups on either side of decimal point - trivially consistent.
-            (0, 0) => true,
-            // Integral part has grouped digits, fractional part does not.
-            (_, 0) => frac_size <= int_group_size,
-            // Fractional part has grouped digits, integral part does not.
-            (0, _) => int_size <= frac_group_size,
-            // Both parts have grouped digits. Groups should be the same size.
-            (_, _) => int_group_size == frac_group_size,
+    let mut normalized = stripped;
+    if zero_exponent {
+        // keep the literal a float if the mantissa has no decimal point
+        if !normalized.contains('.') {
+            normalized.push_str(".0");
         }
+    } else if !exponent.is_empty() {
+        normalized.push('e');
+        normalized.push_str(&exponent[1..]);
     }
+    let normalized = DigitInfo {
+        digits: &normalized,
+        ..*digit_info
+    };
+    let hint = normalized.grouping_hint_by(if portable { 0 } else { normalized.radix.suggest_grouping() });
 
-    /// Performs lint on `digits` (no decimal point) and returns the group
-    /// size on success or `WarningType` when emitting a warning.
-    fn do_lint(digits: &str) -> Result<usize, WarningType> {
+    let (lint, msg, _) = applied[0];
+    span_lint_and_then(cx, lint, lit.span, msg, |db| {
+        multispan_sugg(db, "consider normalizing it".to_owned(), vec![(lit.span, hint)]);
+        for &(other, _, note) in &applied[1..] {
+            db.note(&format!("the suggestion also fixes that {} (`{}`)", note, other.name_lower()));
+        }
+    });
+    true
+}
+
+/// Checks whether `shortest` has the integral part of `mantissa`, but fewer
+/// fractional digits, not counting trailing zeros. Infinite values fail the
+/// first check.
+fn is_less_precise(mantissa: &str, shortest: &str) -> bool {
+    let mut parts = mantissa.splitn(2, '.');
+    let mut short_parts = shortest.splitn(2, '.');
+    let same_int_part = parts.next() == short_parts.next();
+    let frac_len = parts.next().map_or(0, |frac| frac.trim_right_matches('0').len());
+    let short_frac_len = short_parts.next().map_or(0, str::len);
+    same_int_part && frac_len > short_frac_len
+}
+
+impl LiteralDigitGrouping {
+    pub fn new(conf: &Conf) -> Self {
+        Self {
+            scopes: Vec::new(),
+            large_digit_group_threshold: conf.large_digit_group_threshold,
+            portable_literals: conf.portable_literals,
+            show_decimal_value: conf.show_decimal_value,
+            suggestion_style: conf.suggestion_style,
+            power_of_two_shift_threshold: conf.power_of_two_shift_threshold,
+            skip_generated_files: conf.skip_generated_files,
+            generated_file: None,
+            lintable_lines: conf.lintable_lines
+                .iter()
+                .filter_map(|entry| LineRange::parse(entry))
+                .collect(),
+            require_grouping_threshold: conf.require_grouping_threshold,
+            ignored_values: conf.literal_representation_ignore_values
+                .iter()
+                .map(|&value| u128::from(value))
+                .collect(),
+            date_like_levels: Vec::new(),
+        }
+    }
+
+    /// Runs the grouping lints over the literals of `krate` without a lint
+    /// context, and returns the span and replacement of each suggestion. The
+    /// literals are visited with the scopes of the lint pass. This lets tests
+    /// check suggestions without going through the driver.
+    pub fn grouping_suggestions(&mut self, krate: &Crate, codemap: &CodeMap) -> Vec<(syntax_pos::Span, String)> {
+        let mut collector = SuggestionCollector {
+            pass: self,
+            codemap: codemap,
+            suggestions: Vec::new(),
+        };
+        walk_crate(&mut collector, krate);
+        let mut suggestions = collector.suggestions;
+        suggestions.sort_by_key(|&(span, _)| span.lo());
+        suggestions
+    }
+
+    /// Adds the grouping suggestion of `lit` to `suggestions`, if it is
+    /// linted.
+    fn push_grouping_suggestion(
+        &self,
+        lit: &Lit,
+        codemap: &CodeMap,
+        suggestions: &mut Vec<(syntax_pos::Span, String)>,
+    ) {
+        if_let_chain! {[
+            let Some((src, float)) = lintable_snippet(lit, codemap),
+            let Some(digit_info) = DigitInfo::checked_new(&src, float),
+            self.grouping_warning(lit, &src, &digit_info).is_some(),
+        ], {
+            let hint = digit_info.normalized_hint();
+            debug_assert!(is_well_formed_hint(&hint, float), "malformed suggestion `{}`", hint);
+            suggestions.push((lit.span, hint));
+        }}
+    }
+
+    /// Returns the metadata of the lints of this pass.
+    pub fn lint_metadata(&self) -> &'static [LintMetadata] {
+        &LINT_METADATA
+    }
+
+    /// Checks whether the literals at `span` are left alone, because they were
+    /// written by someone else: in an external macro, unless passed to it, or
+    /// in a generated file, or because they aren't on the lines to lint. Every
+    /// hook that lints starts with this.
+    fn should_skip(&mut self, cx: &EarlyContext, span: syntax_pos::Span) -> bool {
+        (in_external_macro(cx, span) && !is_macro_argument(span))
+            || (self.skip_generated_files && self.is_generated(cx, span))
+            || !self.is_lintable_line(cx, span)
+    }
+
+    /// Checks whether `span` starts on one of the configured lintable lines,
+    /// which is any line if none are configured.
+    fn is_lintable_line(&self, cx: &EarlyContext, span: syntax_pos::Span) -> bool {
+        if self.lintable_lines.is_empty() {
+            return true;
+        }
+        let loc = cx.sess().codemap().lookup_char_pos(span.lo());
+        self.lintable_lines
+            .iter()
+            .any(|range| range.contains(&loc.file.name, loc.line))
+    }
+
+    /// Checks whether `span` is in a generated file. The result for the last
+    /// file is kept, as most literals are next to the one checked before.
+    fn is_generated(&mut self, cx: &EarlyContext, span: syntax_pos::Span) -> bool {
+        let filemap = cx.sess().codemap().lookup_char_pos(span.lo()).file;
+        if let Some((start_pos, generated)) = self.generated_file {
+            if start_pos == filemap.start_pos {
+                return generated;
+            }
+        }
+        let generated = is_generated_file(&filemap);
+        self.generated_file = Some((filemap.start_pos, generated));
+        generated
+    }
+
+    /// Records a literal in the innermost scope. Returns false if the same
+    /// literal was already seen there, e.g. because a local macro expanded
+    /// its argument twice.
+    fn record(&mut self, span: syntax_pos::Span) -> bool {
+        match self.scopes.last_mut() {
+            Some(scope) => scope.literals.insert(span),
+            None => true,
+        }
+    }
+
+    /// Remembers the literals of `expr` used as a mask, an array element or
+    /// an index. The operands are visited after `expr`, so the checks of a
+    /// literal find them in the scope, which keeps the warnings in source
+    /// order.
+    fn record_context(&mut self, expr: &Expr) {
+        let scope = match self.scopes.last_mut() {
+            Some(scope) => scope,
+            None => return,
+        };
+        match expr.node {
+            ExprKind::Binary(op, ref left, ref right) => match op.node {
+                BinOpKind::BitAnd | BinOpKind::BitOr | BinOpKind::BitXor => {
+                    scope.mask_literals.extend(mask_literal(left, right));
+                    scope.mask_literals.extend(mask_literal(right, left));
+                },
+                _ => (),
+            },
+            ExprKind::Array(ref elements) => {
+                scope.array_literals.extend(elements.iter().filter_map(|element| match element.node {
+                    ExprKind::Lit(ref lit) => Some(lit.span),
+                    _ => None,
+                }))
+            },
+            ExprKind::Index(_, ref index) => if let ExprKind::Lit(ref lit) = index.node {
+                scope.index_literals.insert(lit.span);
+            },
+            _ => (),
+        }
+    }
+
+    /// Runs the checks of a literal, which `digit_info` is parsed from its
+    /// source `src`.
+    fn check_parsed_lit(&self, cx: &EarlyContext, lit: &Lit, src: &str, digit_info: &DigitInfo) {
+        // Literals normalized by a single suggestion are not checked by the
+        // lints it covers.
+        if !check_float_normalizations(cx, lit, digit_info, self.portable_literals) {
+            if self.portable_literals {
+                check_portable(cx, lit, digit_info);
+            } else {
+                self.check_lit(cx, lit, src, digit_info);
+            }
+            check_redundant_exponent(cx, lit, digit_info);
+            check_padded_float(cx, lit, digit_info);
+            check_exponent_marker(cx, lit, digit_info);
+        }
+        check_float_precision(cx, lit, digit_info);
+        check_verbose_zero(cx, lit, digit_info);
+        check_implicit_float(cx, lit, digit_info);
+        check_identifier_like(cx, lit, digit_info);
+        check_date_like(cx, lit, digit_info);
+        check_time_segments(cx, lit, digit_info);
+        let (mask, element) = self.scopes.last().map_or((false, false), |scope| {
+            (scope.mask_literals.contains(&lit.span), scope.array_literals.contains(&lit.span))
+        });
+        if mask {
+            check_mask_suffix(cx, lit, digit_info);
+        } else if !element {
+            check_radix_prefix(cx, lit, digit_info);
+        }
+    }
+
+    fn check_lit(&self, cx: &EarlyContext, lit: &Lit, src: &str, digit_info: &DigitInfo) {
+        if let Some((warning_type, notes)) = self.grouping_warning(lit, src, digit_info) {
+            warning_type.display(digit_info, &notes, cx, &lit.span);
+        }
+    }
+
+    /// Returns the grouping warning of a literal, if any, and what to note
+    /// about it.
+    fn grouping_warning(&self, lit: &Lit, src: &str, digit_info: &DigitInfo) -> Option<(WarningType, Notes)> {
+        let notes = Notes {
+            show_decimal_value: self.show_decimal_value,
+            index: self.scopes
+                .last()
+                .map_or(false, |scope| scope.index_literals.contains(&lit.span)),
+            suggestion_style: self.suggestion_style,
+        };
+        self.check_grouping(digit_info, src)
+            .err()
+            .map(|warning_type| (warning_type, notes))
+    }
+
+    /// Checks the grouping of the literal `src`, which `digit_info` is made
+    /// of.
+    fn check_grouping(&self, digit_info: &DigitInfo, src: &str) -> Result<(), WarningType> {
+        if digit_info.int_value().map_or(false, |value| self.ignored_values.contains(&value)) {
+            return Ok(());
+        }
+        if !digit_info.float {
+            // A date grouped like one is meant to be read that way, if dates
+            // are linted at all.
+            let dates_linted = self.date_like_levels
+                .last()
+                .map_or(DATE_LIKE_LITERAL.default_level, |&level| level) != Level::Allow;
+            if dates_linted && is_date_grouping(digit_info) {
+                return Ok(());
+            }
+            return self.do_lint(digit_info).map(|_| ());
+        }
+
+        // Separate the mantissa into integral and fractional parts. The
+        // exponent is left alone.
+        let mantissa = digit_info.split_exponent().0;
+        let parts: Vec<&str> = mantissa.split_terminator('.').collect();
+
+        // Lint integral and fractional parts separately, and then check consistency of digit
+        // groups if both pass. Whichever check fails first is reported, so that there is a
+        // single warning per literal.
+        let integral = if mantissa.contains("_.") {
+            Err(WarningType::SeparatorBeforePoint)
+        } else {
+            self.do_lint(&DigitInfo {
+                digits: parts[0],
+                ..*digit_info
+            })
+        };
+        integral.and_then(|integral_group_size| {
+            if parts.len() > 1 {
+                // Lint the fractional part of literal just like integral part, but reversed.
+                let fractional_part = &parts[1].chars().rev().collect::<String>();
+                let fractional_group_size = self.do_lint(&DigitInfo {
+                    digits: fractional_part,
+                    ..*digit_info
+                })?;
+                // Both parts are grouped consistently on their own. Together they are if
+                // regrouping the literal by the size of their groups doesn't change it.
+                let group_size = cmp::max(integral_group_size, fractional_group_size);
+                if group_size != 0 && digit_info.grouping_hint_by(group_size) != src {
+                    return Err(WarningType::InconsistentDigitGrouping);
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Performs lint on the digits of `digit_info`, which must not contain a
+    /// decimal point, and returns the group size on success or `WarningType`
+    /// when emitting a warning.
+    fn do_lint(&self, digit_info: &DigitInfo) -> Result<usize, WarningType> {
+        let (digits, radix) = (digit_info.digits, &digit_info.radix);
+        debug_assert!(
+            digits.chars().all(|c| radix.is_valid_digit(c) || c == '_'),
+            "`{}` is not made of {:?} digits",
+            digits,
+            radix
+        );
         // Grab underscore indices with respect to the units digit.
         let underscore_positions: Vec<usize> = digits
             .chars()
@@ -318,9 +2159,18 @@ impl LiteralDigitGrouping {
             .filter_map(|(idx, digit)| if digit == '_' { Some(idx) } else { None })
             .collect();
 
+        // With at least as many underscores as digits, like in `1____`, there
+        // is no meaningful group size to check.
+        if underscore_positions.len() >= digits.len() - underscore_positions.len() {
+            return Ok(0);
+        }
+
         if underscore_positions.is_empty() {
-            // Check if literal needs underscores.
-            if digits.len() > 4 {
+            // Check if literal needs underscores. A required grouping only
+            // applies to literals long enough to have more than one group.
+            let required = self.require_grouping_threshold != 0 && digits.len() > radix.suggest_grouping()
+                && digits.len() as u64 >= self.require_grouping_threshold;
+            if required || digits.len() > radix.unreadable_threshold() {
                 Err(WarningType::UnreadableLiteral)
             } else {
                 Ok(0)
@@ -334,10 +2184,19 @@ impl LiteralDigitGrouping {
                 // number of digits to the left of the last group cannot be bigger than group size.
                 && (digits.len() - underscore_positions.last().expect("there's at least one element") <= group_size + 1);
 
+            // Single binary or octal digits may stand for bits or permissions.
+            let per_digit = match *radix {
+                Radix::Binary | Radix::Octal => false,
+                Radix::Decimal | Radix::Hexadecimal => {
+                    group_size == 1 && digits.len() - underscore_positions.len() > radix.suggest_grouping()
+                },
+            };
             if !consistent {
                 return Err(WarningType::InconsistentDigitGrouping);
-            } else if group_size > 4 {
+            } else if group_size as u64 > self.large_digit_group_threshold {
                 return Err(WarningType::LargeDigitGroups);
+            } else if per_digit {
+                return Err(WarningType::SmallDigitGroups);
             }
             Ok(group_size)
         }
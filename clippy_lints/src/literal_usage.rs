@@ -0,0 +1,1206 @@
+//! Lints concerned with how integral or floating-point literals are used,
+//! which, unlike the grouping lints, need type information.
+
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::map::Node::NodeLocal;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use syntax::ast::{FloatTy, IntTy, LitIntType, LitKind, NodeId, StrStyle, UintTy};
+use syntax::codemap::Span;
+use literal_digit_grouping::{DigitInfo, Radix};
+use types::int_ty_to_nbits;
+use utils::sugg::Sugg;
+use utils::{get_enclosing_block, get_parent_expr, in_macro, match_def_path, paths, snippet, snippet_opt,
+            span_help_and_lint, span_lint_and_sugg, span_lint_and_then, span_lint_denying_default_and_then};
+
+/// **What it does:** Checks for `u8` bindings initialized with a decimal
+/// integer literal in the printable ASCII range, e.g. `let c: u8 = 65;`.
+///
+/// **Why is this bad?** A byte literal like `b'A'` tells the reader which
+/// character is meant, `65` does not.
+///
+/// **Known problems:** Only `let` bindings are checked. Bindings used in
+/// arithmetic are assumed to be numbers and are not linted.
+///
+/// **Example:**
+/// ```rust
+/// let c: u8 = 65;
+/// ```
+declare_lint! {
+    pub ASCII_AS_INTEGER_LITERAL,
+    Allow,
+    "`u8` character initialized with an integer literal instead of a byte literal"
+}
+
+/// **What it does:** Checks for bit shifts by a literal amount that is not
+/// smaller than the bit width of the shifted type, e.g. `1u32 << 40`.
+///
+/// **Why is this bad?** Such a shift overflows: it panics in debug builds and
+/// its result is unspecified otherwise.
+///
+/// This lint is denied by default if `safety-critical` is set in the
+/// configuration.
+///
+/// **Known problems:** Only literal shift amounts are checked.
+///
+/// **Example:**
+/// ```rust
+/// let x = 1u32 << 40;
+/// ```
+declare_lint! {
+    pub SHIFT_EXCEEDS_WIDTH,
+    Warn,
+    "shifting by a literal amount not smaller than the bit width of the type"
+}
+
+/// **What it does:** Checks for `From::from` calls that build an enum with
+/// two unit variants out of the literal `0` or `1`, e.g. `Switch::from(1)`.
+///
+/// **Why is this bad?** The enum gives both states a name, which says more
+/// than the number does.
+///
+/// **Known problems:** Only direct `TheEnum::from(..)` calls are checked.
+/// The suggestion assumes that `0` maps to the first variant and `1` to the
+/// second one, although the `From` implementation may do otherwise.
+///
+/// **Example:**
+/// ```rust
+/// enum Switch { Off, On }
+/// impl From<u8> for Switch { .. }
+/// let s = Switch::from(1);
+/// ```
+/// Could be written as:
+/// ```rust
+/// let s = Switch::On;
+/// ```
+declare_lint! {
+    pub BOOL_LIKE_ENUM_FROM_LITERAL,
+    Allow,
+    "two-variant enum built from the literal `0` or `1` instead of naming the variant"
+}
+
+/// **What it does:** Checks for arithmetic on suffixed integer literals that
+/// overflows the type of the suffix, but not the wider type the result is
+/// cast to, e.g. `(1u32 << 40) as u64`.
+///
+/// This lint is denied by default if `safety-critical` is set in the
+/// configuration.
+///
+/// **Why is this bad?** The computation is done in the narrow type and
+/// overflows before the cast, even though the cast suggests that the wide
+/// result was intended.
+///
+/// **Known problems:** Only binary operations on two literals are checked.
+///
+/// **Example:**
+/// ```rust
+/// const X: u64 = (1u32 << 40) as u64;
+/// ```
+/// Could be written as:
+/// ```rust
+/// const X: u64 = 1u64 << 40;
+/// ```
+declare_lint! {
+    pub NARROW_LITERAL_SUFFIX,
+    Warn,
+    "literal suffix too narrow for the computation whose result is cast to a wider type"
+}
+
+/// **What it does:** Checks for `Duration::from_secs` and
+/// `Duration::from_millis` calls whose literal argument looks like it was
+/// meant for the other one, i.e. 1000 days or more in seconds, or the
+/// number of seconds of a minute or an hour in milliseconds.
+///
+/// **Why is this bad?** Mixing up the unit makes the duration off by a factor
+/// of 1000.
+///
+/// **Known problems:** This is a heuristic. A timeout of 60 milliseconds may
+/// be just what was meant.
+///
+/// **Example:**
+/// ```rust
+/// let timeout = Duration::from_millis(60);
+/// let period = Duration::from_secs(86_400_000);
+/// ```
+declare_lint! {
+    pub SUSPICIOUS_DURATION_LITERAL,
+    Allow,
+    "`Duration` literal that looks like it was given in the wrong unit"
+}
+
+/// **What it does:** Checks for explicit discriminants of enums with an
+/// integral `#[repr]` that do not fit the representation type, e.g.
+/// `#[repr(u8)] enum E { A = 300 }`.
+///
+/// **Why is this bad?** The discriminant silently wraps around, so the
+/// variant has a different value than the one written down, and may even
+/// share it with another variant.
+///
+/// This lint is denied by default if `safety-critical` is set in the
+/// configuration.
+///
+/// **Known problems:** Only literal discriminants are checked.
+///
+/// **Example:**
+/// ```rust
+/// #[repr(u8)]
+/// enum Status {
+///     Ok = 200,
+///     NotFound = 404,
+/// }
+/// ```
+declare_lint! {
+    pub DISCRIMINANT_OVERFLOWS_REPR,
+    Warn,
+    "enum discriminant literal out of range for the `#[repr]` type of the enum"
+}
+
+/// **What it does:** Checks for string literals of a number with a comma as
+/// the decimal separator parsed as a float, e.g. `"1,5".parse::<f64>()`.
+///
+/// **Why is this bad?** `parse` only accepts a decimal point, so this always
+/// returns an error.
+///
+/// **Known problems:** A comma used as a thousands separator, as in `"1,000"`,
+/// is reported just the same.
+///
+/// **Example:**
+/// ```rust
+/// let ratio = "1,5".parse::<f64>();
+/// ```
+declare_lint! {
+    pub COMMA_DECIMAL_SEPARATOR,
+    Warn,
+    "float parsed from a string literal using a comma as the decimal separator"
+}
+
+/// **What it does:** Checks for functions converting between seconds and
+/// milliseconds with the literal `1000` more than once, as in `secs * 1000`
+/// or `millis / 1000`.
+///
+/// **Why is this bad?** The same magic number spread over a function hides
+/// that it is always the same unit conversion. A named constant says which
+/// one it is.
+///
+/// **Known problems:** Any multiplication or division by `1000` is taken
+/// for a time conversion.
+///
+/// **Example:**
+/// ```rust
+/// let timeout_ms = timeout_secs * 1000;
+/// let elapsed_secs = elapsed_ms / 1000;
+/// ```
+/// Could be written as:
+/// ```rust
+/// const MILLIS_PER_SEC: u64 = 1_000;
+/// let timeout_ms = timeout_secs * MILLIS_PER_SEC;
+/// let elapsed_secs = elapsed_ms / MILLIS_PER_SEC;
+/// ```
+declare_lint! {
+    pub REPEATED_TIME_CONVERSION,
+    Allow,
+    "`* 1000` or `/ 1000` repeated in a function instead of a named constant"
+}
+
+/// **What it does:** Checks for integer literals that are the `MIN` or `MAX`
+/// of their type written out, e.g. `2147483647` for an `i32`.
+///
+/// **Why is this bad?** A digit too many or too few is hard to spot, while
+/// `std::i32::MAX` says what is meant.
+///
+/// **Known problems:** The bounds of 8-bit types, like `255`, are common
+/// numbers, so they are only linted with a suffix, as in `255u8`. The bounds
+/// of `isize` and `usize` depend on the target and are not linted.
+///
+/// **Example:**
+/// ```rust
+/// let limit: i32 = 2147483647;
+/// ```
+declare_lint! {
+    pub INTEGER_BOUND_LITERAL,
+    Allow,
+    "integer literal that is the `MIN` or `MAX` of its type, e.g. `2147483647`"
+}
+
+/// **What it does:** Checks for integer divisions by an unsuffixed literal, or
+/// of one, whose result is cast to a float, e.g. `(total / 2) as f64`.
+///
+/// **Why is this bad?** The division rounds towards zero before the cast, so
+/// the fractional part is lost. Most likely, a float division was meant.
+///
+/// **Known problems:** Sometimes the rounding is intended.
+///
+/// **Example:**
+/// ```rust
+/// let average = (total / 2) as f64;
+/// ```
+/// Could be written as:
+/// ```rust
+/// let average = total as f64 / 2.0;
+/// ```
+declare_lint! {
+    pub INTEGER_DIVISION_TO_FLOAT,
+    Allow,
+    "integer division by a literal cast to a float, e.g. `(total / 2) as f64`"
+}
+
+/// **What it does:** Checks for integral values too precise for the float type
+/// they end up in, e.g. `9007199254740993.0` or `16777217 as f32`.
+///
+/// **Why is this bad?** Above 2^53 for `f64` and 2^24 for `f32`, not every
+/// integer can be represented. The value is silently rounded to a neighbour.
+///
+/// **Known problems:** Only literals, and literals cast to a float, are
+/// checked.
+///
+/// **Example:**
+/// ```rust
+/// let x: f64 = 9007199254740993.0;
+/// ```
+declare_lint! {
+    pub INEXACT_FLOAT_INTEGER,
+    Warn,
+    "integral literal that a float type cannot represent exactly, e.g. `9007199254740993.0`"
+}
+
+/// **What it does:** Checks for `std::process::exit` called with an integer
+/// literal other than `0` or `1`, e.g. `process::exit(3)`.
+///
+/// **Why is this bad?** Beyond plain success and failure, an exit code has a
+/// meaning that callers rely on. A named constant documents it and keeps the
+/// places that exit with it in sync.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// std::process::exit(3);
+/// ```
+declare_lint! {
+    pub EXIT_CODE_LITERAL,
+    Allow,
+    "`process::exit` called with a literal exit code other than `0` or `1`"
+}
+
+/// **What it does:** Checks for integer literals grouped by underscores that
+/// don't fit into their type, e.g. `let x: u8 = 1_000;`.
+///
+/// **Why is this bad?** The value wraps around. Like `overflowing_literals`,
+/// this lint reports it, but also names the smallest type that holds the
+/// value, as its careful grouping suggests it is meant as written.
+///
+/// This lint is denied by default if `safety-critical` is set in the
+/// configuration.
+///
+/// **Known problems:** The overflow is also reported by
+/// `overflowing_literals`.
+///
+/// **Example:**
+/// ```rust
+/// let x: u8 = 1_000;
+/// ```
+declare_lint! {
+    pub OVERFLOWING_GROUPED_LITERAL,
+    Warn,
+    "integer literal grouped by underscores that doesn't fit into its type, e.g. `let x: u8 = 1_000;`"
+}
+
+/// **What it does:** Checks for `f32` literals immediately cast to `f64`,
+/// e.g. `1.5f32 as f64`.
+///
+/// **Why is this bad?** The literal is rounded to `f32` first, so the widened
+/// value may differ from the written one; `0.1f32 as f64` is not `0.1`. The
+/// suffix likely disagrees with the cast by mistake.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let x = 0.1f32 as f64;
+/// ```
+/// Could be written as:
+/// ```rust
+/// let x = 0.1f64;
+/// ```
+declare_lint! {
+    pub WIDENED_F32_LITERAL,
+    Warn,
+    "`f32` literal cast to `f64`, e.g. `0.1f32 as f64`"
+}
+
+/// **What it does:** Checks for suffixed literals in arithmetic with a
+/// binding whose type is annotated, e.g. `x + 3u32` after `let x: u32 = 5;`.
+///
+/// **Why is this bad?** The suffix repeats the type of the annotated binding,
+/// which already determines the type of the literal.
+///
+/// **Known problems:** Only bindings of a `let` with a type annotation are
+/// considered, so the suffix is kept whenever it may drive inference.
+///
+/// **Example:**
+/// ```rust
+/// let x: u32 = 5;
+/// let y = x + 3u32;
+/// ```
+declare_lint! {
+    pub REDUNDANT_OPERAND_SUFFIX,
+    Allow,
+    "literal suffix implied by an annotated binding it is computed with, e.g. `x + 3u32` with `x: u32`"
+}
+
+/// **What it does:** Checks for integer arithmetic taking a literal
+/// percentage, e.g. `value * 7 / 100`.
+///
+/// **Why is this bad?** The bare `7` doesn't say that it is a rate, and the
+/// multiplication is easily dropped or reordered when the expression is
+/// edited. A named constant or a float fraction states the intent.
+///
+/// **Known problems:** Only literal percentages are checked, a binding like
+/// the `pct` of `value * pct / 100` is not.
+///
+/// **Example:**
+/// ```rust
+/// let tax = price * 7 / 100;
+/// ```
+declare_lint! {
+    pub PERCENTAGE_LITERAL,
+    Allow,
+    "integer literal used as a percentage, e.g. `value * 7 / 100`"
+}
+
+/// **What it does:** Checks for `thread::sleep` calls with a literal
+/// `Duration::from_secs` or `Duration::from_millis` longer than the
+/// `max-sleep-secs` configuration, 600 seconds by default.
+///
+/// **Why is this bad?** A thread blocked for an hour can't react to
+/// anything in the meantime, and a long literal sleep is often a mixed-up
+/// unit or a leftover from debugging.
+///
+/// **Known problems:** Only literal durations are checked.
+///
+/// **Example:**
+/// ```rust
+/// thread::sleep(Duration::from_secs(3600));
+/// ```
+declare_lint! {
+    pub LONG_SLEEP_LITERAL,
+    Allow,
+    "`thread::sleep` with a long literal duration, e.g. `thread::sleep(Duration::from_secs(3600))`"
+}
+
+/// This many seconds or more are likely meant as milliseconds.
+const MAX_LIKELY_SECS: u128 = 1000 * 24 * 60 * 60;
+
+#[derive(Copy, Clone)]
+pub struct LiteralUsage {
+    safety_critical: bool,
+    max_sleep_secs: u64,
+}
+
+impl LiteralUsage {
+    pub fn new(safety_critical: bool, max_sleep_secs: u64) -> Self {
+        Self {
+            safety_critical: safety_critical,
+            max_sleep_secs: max_sleep_secs,
+        }
+    }
+}
+
+impl LintPass for LiteralUsage {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(
+            ASCII_AS_INTEGER_LITERAL,
+            SHIFT_EXCEEDS_WIDTH,
+            BOOL_LIKE_ENUM_FROM_LITERAL,
+            SUSPICIOUS_DURATION_LITERAL,
+            NARROW_LITERAL_SUFFIX,
+            DISCRIMINANT_OVERFLOWS_REPR,
+            COMMA_DECIMAL_SEPARATOR,
+            REPEATED_TIME_CONVERSION,
+            INTEGER_BOUND_LITERAL,
+            INTEGER_DIVISION_TO_FLOAT,
+            INEXACT_FLOAT_INTEGER,
+            EXIT_CODE_LITERAL,
+            OVERFLOWING_GROUPED_LITERAL,
+            WIDENED_F32_LITERAL,
+            REDUNDANT_OPERAND_SUFFIX,
+            PERCENTAGE_LITERAL,
+            LONG_SLEEP_LITERAL
+        )
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LiteralUsage {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprBinary(op, ref value, ref amount) | ExprAssignOp(op, ref value, ref amount)
+                if op.node == BiShl || op.node == BiShr =>
+            {
+                check_shift(cx, expr, value, amount, self.safety_critical)
+            },
+            ExprBinary(op, ref left, ref right) | ExprAssignOp(op, ref left, ref right)
+                if op.node == BiAdd || op.node == BiSub || op.node == BiMul || op.node == BiDiv
+                    || op.node == BiRem =>
+            {
+                check_operand_suffix(cx, left, right);
+                check_operand_suffix(cx, right, left);
+                check_percentage(cx, expr);
+            },
+            ExprCall(ref fun, ref args) if args.len() == 1 => {
+                check_enum_from(cx, expr, fun, &args[0]);
+                check_duration(cx, expr, fun, &args[0]);
+                check_sleep(cx, expr, fun, &args[0], self.max_sleep_secs);
+                check_exit_code(cx, fun, &args[0]);
+            },
+            ExprCast(ref inner, _) => {
+                check_narrow_suffix(cx, expr, inner, self.safety_critical);
+                check_division_to_float(cx, expr, inner);
+                check_widened_f32(cx, expr, inner);
+                // Float literals are checked on their own.
+                if_let_chain! {[
+                    let ExprLit(ref lit) = inner.node,
+                    let LitKind::Int(..) = lit.node,
+                ], {
+                    check_inexact_float(cx, expr, lit);
+                }}
+            },
+            ExprLit(ref lit) => {
+                check_bound(cx, expr, lit, false);
+                check_inexact_float(cx, expr, lit);
+                check_grouped_overflow(cx, expr, lit, self.safety_critical);
+            },
+            ExprUnary(UnNeg, ref inner) => if let ExprLit(ref lit) = inner.node {
+                check_bound(cx, expr, lit, true)
+            },
+            ExprMethodCall(ref path, _, ref args) if path.name == "parse" && args.len() == 1 => {
+                check_float_parse(cx, expr, &args[0])
+            },
+            _ => (),
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if_let_chain! {[
+            let Some(ref init) = local.init,
+            !in_macro(init.span),
+            let ExprLit(ref lit) = init.node,
+            let LitKind::Int(value, _) = lit.node,
+            value >= 32 && value <= 126,
+            cx.tables.expr_ty(init).sty == ty::TyUint(UintTy::U8),
+            let PatKind::Binding(_, def_id, _, None) = local.pat.node,
+            let Some(src) = snippet_opt(cx, lit.span),
+            let Some(DigitInfo { radix: Radix::Decimal, .. }) = DigitInfo::checked_new(&src, false),
+            let Some(block) = get_enclosing_block(cx, local.id),
+            !used_in_arithmetic(cx, def_id, block),
+        ], {
+            span_lint_and_sugg(
+                cx,
+                ASCII_AS_INTEGER_LITERAL,
+                init.span,
+                "integer literal used as an ASCII character",
+                "consider using a byte literal",
+                format!("b'{}'", escape_byte(value as u8)),
+            );
+        }}
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        _: Span,
+        _: NodeId,
+    ) {
+        let mut visitor = ThousandVisitor { conversions: Vec::new() };
+        visitor.visit_body(body);
+        if visitor.conversions.len() > 1 {
+            span_help_and_lint(
+                cx,
+                REPEATED_TIME_CONVERSION,
+                visitor.conversions[0],
+                &format!("`1000` is used in {} conversions in this function", visitor.conversions.len()),
+                "consider a named constant, like `const MILLIS_PER_SEC: u64 = 1_000;`",
+            );
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemEnum(ref def, _) = item.node {
+            if cx.tcx.adt_def(cx.tcx.hir.local_def_id(item.id)).repr.int.is_none() {
+                return;
+            }
+            for variant in &def.variants {
+                if let Some(body_id) = variant.node.disr_expr {
+                    let expr = &cx.tcx.hir.body(body_id).value;
+                    let ty = cx.tcx.body_tables(body_id).expr_ty(expr);
+                    check_discriminant(cx, expr, ty, self.safety_critical);
+                }
+            }
+        }
+    }
+}
+
+fn check_shift(cx: &LateContext, expr: &Expr, value: &Expr, amount: &Expr, safety_critical: bool) {
+    if_let_chain! {[
+        let ExprLit(ref lit) = amount.node,
+        let LitKind::Int(shift, _) = lit.node,
+    ], {
+        let ty = cx.tables.expr_ty(value);
+        let nbits = int_ty_to_nbits(ty, cx.tcx);
+        if nbits != 0 && shift >= u128::from(nbits) {
+            span_lint_denying_default_and_then(
+                cx,
+                safety_critical,
+                SHIFT_EXCEEDS_WIDTH,
+                expr.id,
+                expr.span,
+                &format!("shift by {} is out of range for `{}`, which is {} bits wide", shift, ty, nbits),
+                |_| (),
+            );
+        }
+    }}
+}
+
+fn check_enum_from(cx: &LateContext, expr: &Expr, fun: &Expr, arg: &Expr) {
+    if_let_chain! {[
+        let ExprPath(ref qpath) = fun.node,
+        let QPath::TypeRelative(ref ty, ref seg) = *qpath,
+        seg.name == "from",
+        let ExprLit(ref lit) = arg.node,
+        let LitKind::Int(value, _) = lit.node,
+        value < 2,
+        let ty::TyAdt(adt_def, _) = cx.tables.expr_ty(expr).sty,
+        adt_def.is_enum(),
+        adt_def.variants.len() == 2,
+        adt_def.variants.iter().all(|variant| variant.fields.is_empty()),
+        is_from_method(cx, cx.tables.qpath_def(qpath, fun.hir_id).def_id()),
+    ], {
+        let enum_name = snippet(cx, ty.span, "..");
+        span_lint_and_sugg(
+            cx,
+            BOOL_LIKE_ENUM_FROM_LITERAL,
+            expr.span,
+            &format!("`{}` built from the literal `{}`", enum_name, value),
+            "consider naming the variant",
+            format!("{}::{}", enum_name, adt_def.variants[value as usize].name),
+        );
+    }}
+}
+
+fn check_narrow_suffix(cx: &LateContext, expr: &Expr, inner: &Expr, safety_critical: bool) {
+    if_let_chain! {[
+        let ExprBinary(op, ref left, ref right) = inner.node,
+        let ExprLit(ref left_lit) = left.node,
+        let ExprLit(ref right_lit) = right.node,
+        let LitKind::Int(left_value, left_ty) = left_lit.node,
+        let LitKind::Int(right_value, right_ty) = right_lit.node,
+        let Some(value) = eval_int_op(op.node, left_value, right_value),
+    ], {
+        // The type of a shift is the type of its left operand alone.
+        let suffixed = if left_ty != LitIntType::Unsuffixed {
+            left_lit
+        } else if right_ty != LitIntType::Unsuffixed && op.node != BiShl {
+            right_lit
+        } else {
+            return;
+        };
+        let (narrow, wide) = (cx.tables.expr_ty(inner), cx.tables.expr_ty(expr));
+        let (narrow_bits, wide_bits) = (int_ty_to_nbits(narrow, cx.tcx), int_ty_to_nbits(wide, cx.tcx));
+        if narrow_bits == 0 || wide_bits <= narrow_bits || value <= max_value(narrow, narrow_bits)
+            || value > max_value(wide, wide_bits)
+        {
+            return;
+        }
+        if_let_chain! {[
+            let Some(src) = snippet_opt(cx, suffixed.span),
+            let Some(digit_info) = DigitInfo::checked_new(&src, false),
+        ], {
+            let underscore = if digit_info.suffix.map_or(false, |suffix| suffix.starts_with('_')) { "_" } else { "" };
+            let sugg = format!("{}{}{}{}", digit_info.prefix.unwrap_or(""), digit_info.digits, underscore, wide);
+            span_lint_denying_default_and_then(
+                cx,
+                safety_critical,
+                NARROW_LITERAL_SUFFIX,
+                expr.id,
+                suffixed.span,
+                &format!("the computation with this `{}` literal overflows before it is cast to `{}`", narrow, wide),
+                |db| {
+                    db.span_suggestion(suffixed.span, &format!("use a `{}` literal", wide), sugg);
+                },
+            );
+        }}
+    }}
+}
+
+fn check_discriminant(cx: &LateContext, expr: &Expr, ty: Ty, safety_critical: bool) {
+    let (negative, lit) = match expr.node {
+        ExprLit(ref lit) => (false, lit),
+        ExprUnary(UnNeg, ref inner) => match inner.node {
+            ExprLit(ref lit) => (true, lit),
+            _ => return,
+        },
+        _ => return,
+    };
+    if_let_chain! {[
+        let LitKind::Int(value, _) = lit.node,
+        !in_macro(expr.span),
+    ], {
+        let nbits = int_ty_to_nbits(ty, cx.tcx);
+        if nbits == 0 {
+            return;
+        }
+        // `-(MAX + 1)` is the smallest value of a signed type.
+        let limit = if negative {
+            max_value(ty, nbits) + 1
+        } else {
+            max_value(ty, nbits)
+        };
+        if value > limit {
+            span_lint_denying_default_and_then(
+                cx,
+                safety_critical,
+                DISCRIMINANT_OVERFLOWS_REPR,
+                expr.id,
+                expr.span,
+                &format!(
+                    "discriminant `{}` does not fit the `{}` representation of this enum",
+                    snippet(cx, expr.span, ".."),
+                    ty
+                ),
+                |_| (),
+            );
+        }
+    }}
+}
+
+/// Checks whether `lit`, negated if `negative`, is the `MIN` or `MAX` of the
+/// type of `expr`.
+fn check_bound(cx: &LateContext, expr: &Expr, lit: &Lit, negative: bool) {
+    if let LitKind::Int(value, suffix) = lit.node {
+        let ty = cx.tables.expr_ty(expr);
+        match ty.sty {
+            ty::TyInt(IntTy::Is) | ty::TyUint(UintTy::Us) => return,
+            _ => (),
+        }
+        let nbits = int_ty_to_nbits(ty, cx.tcx);
+        if nbits == 0 || (nbits <= 8 && suffix == LitIntType::Unsuffixed) {
+            return;
+        }
+        let max = max_value(ty, nbits);
+        let bound = match ty.sty {
+            _ if !negative && value == max => "MAX",
+            ty::TyInt(_) if negative && value == max + 1 => "MIN",
+            _ => return,
+        };
+        span_lint_and_sugg(
+            cx,
+            INTEGER_BOUND_LITERAL,
+            expr.span,
+            &format!("this is `{}::{}` written out", ty, bound),
+            "use the constant",
+            format!("std::{}::{}", ty, bound),
+        );
+    }
+}
+
+fn check_grouped_overflow(cx: &LateContext, expr: &Expr, lit: &Lit, safety_critical: bool) {
+    if_let_chain! {[
+        let LitKind::Int(value, _) = lit.node,
+        let Some(src) = snippet_opt(cx, lit.span),
+        let Some(digit_info) = DigitInfo::checked_new(&src, false),
+        digit_info.digits.contains('_'),
+    ], {
+        let ty = cx.tables.expr_ty(expr);
+        let nbits = int_ty_to_nbits(ty, cx.tcx);
+        if nbits == 0 {
+            return;
+        }
+        let signed = match ty.sty {
+            ty::TyInt(_) => true,
+            _ => false,
+        };
+        let negative = match get_parent_expr(cx, expr) {
+            Some(parent) => match parent.node {
+                ExprUnary(UnNeg, _) => true,
+                _ => false,
+            },
+            None => false,
+        };
+        // The magnitude of the minimum of a signed type is one more than its maximum.
+        let slack = if signed && negative { 1 } else { 0 };
+        let fits = |ty: Ty, nbits: u64| value <= max_value(ty, nbits) + slack;
+        if fits(ty, nbits) {
+            return;
+        }
+        let wider = [8, 16, 32, 64, 128].iter().find(|&&bits| bits > nbits && fits(ty, bits));
+        span_lint_denying_default_and_then(
+            cx,
+            safety_critical,
+            OVERFLOWING_GROUPED_LITERAL,
+            expr.id,
+            lit.span,
+            &format!("literal `{}` doesn't fit into `{}`", src, ty),
+            |db| if let Some(bits) = wider {
+                db.help(&format!("`{}{}` is the smallest type that holds it", if signed { 'i' } else { 'u' }, bits));
+            },
+        );
+    }}
+}
+
+fn check_division_to_float(cx: &LateContext, expr: &Expr, inner: &Expr) {
+    if_let_chain! {[
+        let ExprBinary(op, ref left, ref right) = inner.node,
+        op.node == BiDiv,
+        let ty::TyFloat(_) = cx.tables.expr_ty(expr).sty,
+        match cx.tables.expr_ty(inner).sty {
+            ty::TyInt(_) | ty::TyUint(_) => true,
+            _ => false,
+        },
+    ], {
+        // One operand is an unsuffixed literal, which gets a fractional part,
+        // the other one is cast.
+        let as_float = |operand: &Expr| -> Option<String> {
+            if_let_chain! {[
+                let ExprLit(ref lit) = operand.node,
+                let LitKind::Int(_, LitIntType::Unsuffixed) = lit.node,
+                let Some(src) = snippet_opt(cx, lit.span),
+            ], {
+                return Some(format!("{}.0", src));
+            }}
+            None
+        };
+        let float = cx.tables.expr_ty(expr);
+        let sugg = match (as_float(left), as_float(right)) {
+            (None, Some(right)) => format!("{} / {}", Sugg::hir(cx, left, "..").as_ty(float), right),
+            (Some(left), None) => format!("{} / {}", left, Sugg::hir(cx, right, "..").as_ty(float)),
+            _ => return,
+        };
+        span_lint_and_sugg(
+            cx,
+            INTEGER_DIVISION_TO_FLOAT,
+            expr.span,
+            &format!("integer division cast to `{}`, which drops the fractional part", float),
+            "if a float division is meant, divide floats instead",
+            sugg,
+        );
+    }}
+}
+
+/// Checks whether the integral value of `lit` can be represented exactly by
+/// the float type of `expr`, which is either `lit` itself or a cast of it.
+fn check_inexact_float(cx: &LateContext, expr: &Expr, lit: &Lit) {
+    let (float_ty, mantissa_bits) = match cx.tables.expr_ty(expr).sty {
+        ty::TyFloat(FloatTy::F32) => ("f32", 24),
+        ty::TyFloat(FloatTy::F64) => ("f64", 53),
+        _ => return,
+    };
+    let value = match lit.node {
+        LitKind::Int(value, _) => value,
+        LitKind::Float(..) | LitKind::FloatUnsuffixed(..) => match float_int_value(cx, lit) {
+            Some(value) => value,
+            None => return,
+        },
+        _ => return,
+    };
+    // The bits from the highest to the lowest set one must fit the mantissa.
+    if value != 0 && 128 - value.leading_zeros() - value.trailing_zeros() > mantissa_bits {
+        let rounded = if mantissa_bits == 24 {
+            (value as f32).to_string()
+        } else {
+            (value as f64).to_string()
+        };
+        span_lint_and_then(
+            cx,
+            INEXACT_FLOAT_INTEGER,
+            lit.span,
+            &format!("`{}` cannot represent `{}` exactly", float_ty, value),
+            |db| {
+                db.note(&format!("it is rounded to `{}`", rounded));
+            },
+        );
+    }
+}
+
+/// Returns the value of a float literal without an exponent and with only
+/// zeros after the decimal point, if any, like `1_000.0`.
+fn float_int_value(cx: &LateContext, lit: &Lit) -> Option<u128> {
+    let src = match snippet_opt(cx, lit.span) {
+        Some(src) => src,
+        None => return None,
+    };
+    let digit_info = match DigitInfo::checked_new(&src, true) {
+        Some(digit_info) => digit_info,
+        None => return None,
+    };
+    let (mantissa, exponent) = digit_info.split_exponent();
+    let mut parts = mantissa.splitn(2, '.');
+    let int_part = parts.next().expect("splitn returns at least one element");
+    let frac_is_zero = parts.next().map_or(true, |frac| frac.chars().all(|c| c == '0' || c == '_'));
+    if !exponent.is_empty() || !frac_is_zero {
+        return None;
+    }
+    DigitInfo {
+        digits: int_part,
+        float: false,
+        ..digit_info
+    }.int_value()
+}
+
+/// Evaluates `left op right` for the operators that can make a value larger,
+/// or returns `None` if even `u128` overflows.
+fn eval_int_op(op: BinOp_, left: u128, right: u128) -> Option<u128> {
+    match op {
+        BiAdd => left.checked_add(right),
+        BiMul => left.checked_mul(right),
+        BiShl if right < 128 => {
+            let value = left << right as u32;
+            if value >> right as u32 == left {
+                Some(value)
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns the largest value of the integral type `ty`, which is `nbits` wide.
+fn max_value(ty: Ty, nbits: u64) -> u128 {
+    match ty.sty {
+        ty::TyInt(_) => (1 << (nbits - 1)) - 1,
+        _ if nbits == 128 => u128::max_value(),
+        _ => (1 << nbits) - 1,
+    }
+}
+
+fn check_duration(cx: &LateContext, expr: &Expr, fun: &Expr, arg: &Expr) {
+    if let Some((value, millis_per_unit)) = literal_duration(cx, fun, arg) {
+        if millis_per_unit == 1000 && value >= MAX_LIKELY_SECS {
+            span_help_and_lint(
+                cx,
+                SUSPICIOUS_DURATION_LITERAL,
+                expr.span,
+                "this duration is 1000 days or longer",
+                "if this is a number of milliseconds, use `Duration::from_millis`",
+            );
+        } else if millis_per_unit == 1 && (value == 60 || value == 3600) {
+            span_help_and_lint(
+                cx,
+                SUSPICIOUS_DURATION_LITERAL,
+                expr.span,
+                &format!("this duration is only {} milliseconds", value),
+                "if this is a number of seconds, use `Duration::from_secs`",
+            );
+        }
+    }
+}
+
+/// Returns the literal argument of a call to `fun`, if it is
+/// `Duration::from_secs` or `Duration::from_millis`, along with the number
+/// of milliseconds in a unit of the argument.
+fn literal_duration(cx: &LateContext, fun: &Expr, arg: &Expr) -> Option<(u128, u128)> {
+    if_let_chain! {[
+        let ExprPath(ref qpath) = fun.node,
+        let Def::Method(def_id) = cx.tables.qpath_def(qpath, fun.hir_id),
+        let ExprLit(ref lit) = arg.node,
+        let LitKind::Int(value, _) = lit.node,
+    ], {
+        if match_def_path(cx.tcx, def_id, &paths::DURATION_FROM_SECS) {
+            return Some((value, 1000));
+        } else if match_def_path(cx.tcx, def_id, &paths::DURATION_FROM_MILLIS) {
+            return Some((value, 1));
+        }
+    }}
+    None
+}
+
+fn check_sleep(cx: &LateContext, expr: &Expr, fun: &Expr, arg: &Expr, max_sleep_secs: u64) {
+    if_let_chain! {[
+        let ExprPath(ref qpath) = fun.node,
+        let Def::Fn(def_id) = cx.tables.qpath_def(qpath, fun.hir_id),
+        match_def_path(cx.tcx, def_id, &paths::THREAD_SLEEP),
+        let ExprCall(ref duration_fun, ref duration_args) = arg.node,
+        duration_args.len() == 1,
+        let Some((value, millis_per_unit)) = literal_duration(cx, duration_fun, &duration_args[0]),
+        value.saturating_mul(millis_per_unit) > max_sleep_secs as u128 * 1000,
+    ], {
+        span_help_and_lint(
+            cx,
+            LONG_SLEEP_LITERAL,
+            expr.span,
+            &format!("this sleep blocks the thread for more than {} seconds", max_sleep_secs),
+            "if the thread should wake up meanwhile, consider waiting with a timeout, e.g. `Condvar::wait_timeout`",
+        );
+    }}
+}
+
+fn check_widened_f32(cx: &LateContext, expr: &Expr, inner: &Expr) {
+    if_let_chain! {[
+        let ExprLit(ref lit) = inner.node,
+        let LitKind::Float(_, FloatTy::F32) = lit.node,
+        let ty::TyFloat(FloatTy::F64) = cx.tables.expr_ty(expr).sty,
+        let Some(src) = snippet_opt(cx, lit.span),
+        let Some(digit_info) = DigitInfo::checked_new(&src, true),
+    ], {
+        let underscore = if digit_info.suffix.map_or(false, |suffix| suffix.starts_with('_')) { "_" } else { "" };
+        span_lint_and_sugg(
+            cx,
+            WIDENED_F32_LITERAL,
+            expr.span,
+            "this `f32` literal is rounded to `f32` before it is cast to `f64`",
+            "use a `f64` literal",
+            format!("{}{}f64", digit_info.digits, underscore),
+        );
+    }}
+}
+
+fn check_operand_suffix(cx: &LateContext, lit_expr: &Expr, binding: &Expr) {
+    if_let_chain! {[
+        let ExprLit(ref lit) = lit_expr.node,
+        is_annotated_binding(cx, binding),
+        cx.tables.expr_ty(lit_expr) == cx.tables.expr_ty(binding),
+        let Some(src) = snippet_opt(cx, lit.span),
+    ], {
+        let (digit_info, float) = match lit.node {
+            LitKind::Int(_, LitIntType::Signed(_)) | LitKind::Int(_, LitIntType::Unsigned(_)) => {
+                (DigitInfo::new(&src, false), false)
+            },
+            LitKind::Float(..) => (DigitInfo::new(&src, true), true),
+            _ => return,
+        };
+        let digits = digit_info.digits.trim_right_matches('_');
+        // `1f64` would become an integer without its suffix.
+        let point = if float && !digits.contains(|c| c == '.' || c == 'e' || c == 'E') { ".0" } else { "" };
+        span_lint_and_sugg(
+            cx,
+            REDUNDANT_OPERAND_SUFFIX,
+            lit.span,
+            "the suffix of this literal is implied by the type of the binding it is computed with",
+            "remove the suffix",
+            format!("{}{}{}", digit_info.prefix.unwrap_or(""), digits, point),
+        );
+    }}
+}
+
+fn check_percentage(cx: &LateContext, expr: &Expr) {
+    if_let_chain! {[
+        let ExprBinary(div, ref product, ref divisor) = expr.node,
+        div.node == BiDiv,
+        int_lit_value(divisor) == Some(100),
+        let ExprBinary(mul, ref left, ref right) = product.node,
+        mul.node == BiMul,
+        let Some(percent) = int_lit_value(left).or_else(|| int_lit_value(right)),
+    ], {
+        span_help_and_lint(
+            cx,
+            PERCENTAGE_LITERAL,
+            expr.span,
+            &format!("`{}` is used as a percentage", percent),
+            &format!(
+                "consider a named constant, like `const RATE_PERCENT: u32 = {};`, or the fraction `{}`",
+                percent,
+                percent_as_fraction(percent)
+            ),
+        );
+    }}
+}
+
+fn int_lit_value(expr: &Expr) -> Option<u128> {
+    if let ExprLit(ref lit) = expr.node {
+        if let LitKind::Int(value, _) = lit.node {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Formats `percent / 100` as a float literal, e.g. `0.07` for `7`.
+fn percent_as_fraction(percent: u128) -> String {
+    let fraction = format!("{}.{:02}", percent / 100, percent % 100);
+    let fraction = fraction.trim_right_matches('0');
+    if fraction.ends_with('.') {
+        format!("{}0", fraction)
+    } else {
+        fraction.to_owned()
+    }
+}
+
+/// Checks whether `expr` is a local binding declared by a `let` with a type
+/// annotation, like the `x` of `let x: u32 = 5;`.
+fn is_annotated_binding(cx: &LateContext, expr: &Expr) -> bool {
+    if_let_chain! {[
+        let ExprPath(ref qpath) = expr.node,
+        let Def::Local(def_id) = cx.tables.qpath_def(qpath, expr.hir_id),
+        let Some(pat_id) = cx.tcx.hir.as_local_node_id(def_id),
+        let Some(NodeLocal(local)) = cx.tcx.hir.find(cx.tcx.hir.get_parent_node(pat_id)),
+    ], {
+        return local.pat.id == pat_id && local.ty.is_some();
+    }}
+    false
+}
+
+fn check_exit_code(cx: &LateContext, fun: &Expr, arg: &Expr) {
+    if_let_chain! {[
+        let ExprPath(ref qpath) = fun.node,
+        let Def::Fn(def_id) = cx.tables.qpath_def(qpath, fun.hir_id),
+        match_def_path(cx.tcx, def_id, &paths::PROCESS_EXIT),
+        let ExprLit(ref lit) = arg.node,
+        let LitKind::Int(value, _) = lit.node,
+        value > 1,
+    ], {
+        span_help_and_lint(
+            cx,
+            EXIT_CODE_LITERAL,
+            arg.span,
+            &format!("process exits with the literal code `{}`", value),
+            "consider a named constant that documents what this code means",
+        );
+    }}
+}
+
+fn check_float_parse(cx: &LateContext, expr: &Expr, receiver: &Expr) {
+    if_let_chain! {[
+        let ExprLit(ref lit) = receiver.node,
+        let LitKind::Str(ref string, StrStyle::Cooked) = lit.node,
+        is_comma_decimal(&string.as_str()),
+        let ty::TyAdt(_, substs) = cx.tables.expr_ty(expr).sty,
+        let ty::TyFloat(_) = substs.type_at(0).sty,
+    ], {
+        span_lint_and_sugg(
+            cx,
+            COMMA_DECIMAL_SEPARATOR,
+            receiver.span,
+            "this string uses a comma as the decimal separator, which `parse` rejects",
+            "use a decimal point",
+            format!("\"{}\"", string.as_str().replace(',', ".")),
+        );
+    }}
+}
+
+/// Checks whether `number` is digits, a single comma and more digits, as in
+/// `1,5`.
+fn is_comma_decimal(number: &str) -> bool {
+    let mut parts = number.splitn(2, ',');
+    let all_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_digit(10));
+    match (parts.next(), parts.next()) {
+        (Some(int_part), Some(frac_part)) => all_digits(int_part) && all_digits(frac_part),
+        _ => false,
+    }
+}
+
+/// Checks whether `def_id` is `From::from`, or its implementation for some
+/// type.
+fn is_from_method(cx: &LateContext, def_id: DefId) -> bool {
+    let trait_id = match cx.tcx.impl_of_method(def_id) {
+        Some(impl_id) => cx.tcx.trait_id_of_impl(impl_id),
+        None => cx.tcx.trait_of_item(def_id),
+    };
+    trait_id.map_or(false, |trait_id| match_def_path(cx.tcx, trait_id, &paths::FROM_TRAIT))
+}
+
+/// Returns the byte as it would be written between the quotes of a byte
+/// literal.
+fn escape_byte(byte: u8) -> String {
+    match byte {
+        b'\'' => "\\'".to_owned(),
+        b'\\' => "\\\\".to_owned(),
+        _ => (byte as char).to_string(),
+    }
+}
+
+fn used_in_arithmetic<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, id: DefId, block: &'tcx Block) -> bool {
+    let mut v = ArithmeticVisitor {
+        cx: cx,
+        id: id,
+        used: false,
+    };
+    v.visit_block(block);
+    v.used
+}
+
+/// Collects the spans of multiplications and divisions by the literal `1000`.
+struct ThousandVisitor {
+    conversions: Vec<Span>,
+}
+
+impl<'tcx> Visitor<'tcx> for ThousandVisitor {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprBinary(op, ref l, ref r) = expr.node {
+            let by_thousand = match op.node {
+                BiMul => is_thousand(l) || is_thousand(r),
+                BiDiv => is_thousand(r),
+                _ => false,
+            };
+            if by_thousand && !in_macro(expr.span) {
+                self.conversions.push(expr.span);
+            }
+        }
+        walk_expr(self, expr);
+    }
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+fn is_thousand(expr: &Expr) -> bool {
+    if let ExprLit(ref lit) = expr.node {
+        if let LitKind::Int(1000, _) = lit.node {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_arithmetic(op: BinOp_) -> bool {
+    match op {
+        BiAdd | BiSub | BiMul | BiDiv | BiRem | BiBitAnd | BiBitOr | BiBitXor | BiShl | BiShr => true,
+        _ => false,
+    }
+}
+
+/// Looks for a local variable used as an operand of an arithmetic or bitwise
+/// operator.
+struct ArithmeticVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: DefId,
+    used: bool,
+}
+
+impl<'a, 'tcx> ArithmeticVisitor<'a, 'tcx> {
+    fn is_var(&self, expr: &Expr) -> bool {
+        if let ExprPath(ref qpath) = expr.node {
+            self.cx.tables.qpath_def(qpath, expr.hir_id).def_id() == self.id
+        } else {
+            false
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ArithmeticVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprBinary(op, ref l, ref r) = expr.node {
+            if is_arithmetic(op.node) && (self.is_var(l) || self.is_var(r)) {
+                self.used = true;
+                return;
+            }
+        }
+        if let ExprAssignOp(op, ref l, ref r) = expr.node {
+            if is_arithmetic(op.node) && (self.is_var(l) || self.is_var(r)) {
+                self.used = true;
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
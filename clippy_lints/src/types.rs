@@ -520,7 +520,7 @@ declare_lint! {
 
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
-fn int_ty_to_nbits(typ: Ty, tcx: TyCtxt) -> u64 {
+pub fn int_ty_to_nbits(typ: Ty, tcx: TyCtxt) -> u64 {
     match typ.sty {
         ty::TyInt(i) => match i {
             IntTy::Is => tcx.data_layout.pointer_size.bits(),
@@ -48,6 +48,13 @@ pub enum Error {
     ),
     /// There is an unknown key is the file.
     UnknownKey(String),
+    /// A value of the right type that makes no sense.
+    Invalid(
+        /// The name of the key.
+        &'static str,
+        /// What is wrong with the value.
+        String,
+    ),
 }
 
 impl fmt::Display for Error {
@@ -59,6 +66,7 @@ impl fmt::Display for Error {
                 write!(f, "`{}` is expected to be a `{}` but is a `{}`", key, expected, got)
             },
             Error::UnknownKey(ref key) => write!(f, "unknown key `{}`", key),
+            Error::Invalid(key, ref reason) => write!(f, "invalid value for `{}`: {}", key, reason),
         }
     }
 }
@@ -134,10 +142,21 @@ macro_rules! define_Conf {
     }};
 
     // provide a nicer syntax to declare the default value of `Vec<String>` variables
-    (DEFAULT Vec<String>, $e: expr) => { $e.iter().map(|&e| e.to_owned()).collect() };
+    (DEFAULT Vec<String>, $e: expr) => { $e.iter().map(|&e: &&str| e.to_owned()).collect() };
+    (DEFAULT Vec<u64>, $e: expr) => { $e.to_vec() };
     (DEFAULT $ty: ty, $e: expr) => { $e };
 }
 
+/// How the grouping lints word their suggestions.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SuggestionStyle {
+    /// Only the replacement, as plain help text, for tools that render their own UI.
+    Terse,
+    /// A `consider` lead-in before the replacement.
+    Verbose,
+}
+
 define_Conf! {
     /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about
     (blacklisted_names, "blacklisted_names", ["foo", "bar", "baz", "quux"] => Vec<String>),
@@ -172,6 +191,72 @@ define_Conf! {
     (enum_variant_name_threshold, "enum_variant_name_threshold", 3 => u64),
     /// Lint: LARGE_ENUM_VARIANT. The maximum size of a emum's variant to avoid box suggestion
     (enum_variant_size_threshold, "enum_variant_size_threshold", 200 => u64),
+    /// Lint: LARGE_DIGIT_GROUPS. The maximum number of digits a group of a literal may have
+    (large_digit_group_threshold, "large_digit_group_threshold", 4 => u64),
+    /// Lint: PORTABLE_LITERAL. Whether to lint underscores in literals instead of suggesting them
+    (portable_literals, "portable_literals", false => bool),
+    /// Lint: PREFER_SHIFT_FOR_POWER_OF_TWO. The smallest power of two to suggest writing as a shift
+    (power_of_two_shift_threshold, "power_of_two_shift_threshold", 1024 => u64),
+    /// Lint: UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING, LARGE_DIGIT_GROUPS, SEPARATOR_BEFORE_DECIMAL_POINT. Whether to show the decimal value of long binary and hexadecimal literals
+    (show_decimal_value, "show_decimal_value", false => bool),
+    /// Lint: SHIFT_EXCEEDS_WIDTH, NARROW_LITERAL_SUFFIX, DISCRIMINANT_OVERFLOWS_REPR, OVERFLOWING_GROUPED_LITERAL. Whether to deny the correctness-oriented literal lints by default
+    (safety_critical, "safety_critical", false => bool),
+    /// Lint: UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING, LARGE_DIGIT_GROUPS, SEPARATOR_BEFORE_DECIMAL_POINT. How to word the suggestions of the grouping lints, `"verbose"` or `"terse"`
+    (suggestion_style, "suggestion_style", ::utils::conf::SuggestionStyle::Verbose => ::utils::conf::SuggestionStyle),
+    /// Lint: literal_digit_grouping::*. Whether the literal lints skip generated files, i.e. files in `OUT_DIR` or with an `@generated` comment at the top
+    (skip_generated_files, "skip_generated_files", false => bool),
+    /// Lint: literal_digit_grouping::*. If not empty, the literal lints only check these lines, given as `"path:first-last"` or `"path:line"`, where the path is a suffix of the file name
+    (lintable_lines, "lintable_lines", [] => Vec<String>),
+    /// Lint: UNREADABLE_LITERAL. If not zero, ungrouped literals with at least this many digits are linted, even if shorter than the default length
+    (require_grouping_threshold, "require_grouping_threshold", 0 => u64),
+    /// Lint: UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING, LARGE_DIGIT_GROUPS, SEPARATOR_BEFORE_DECIMAL_POINT. The values of literals the grouping lints don't check, however they are written, e.g. `[3735928559]` for both `0xDEADBEEF` and `3735928559`
+    (literal_representation_ignore_values, "literal_representation_ignore_values", [] => Vec<u64>),
+    /// Lint: LONG_SLEEP_LITERAL. The longest sleep, in seconds, that `thread::sleep` may be called with for a literal duration
+    (max_sleep_secs, "max_sleep_secs", 600 => u64),
+}
+
+/// A range of lines in a file, from a `lintable-lines` entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineRange {
+    /// A suffix of the names of the files the range applies to.
+    pub file: String,
+    /// The first line of the range, counting from one.
+    pub first: usize,
+    /// The last line of the range, inclusive.
+    pub last: usize,
+}
+
+impl LineRange {
+    /// Parses a `lintable-lines` entry like `src/lib.rs:10-20` or
+    /// `src/lib.rs:10`.
+    pub fn parse(entry: &str) -> Option<Self> {
+        let mut parts = entry.rsplitn(2, ':');
+        let lines = parts.next().expect("rsplitn returns at least one element");
+        let file = match parts.next() {
+            Some(file) if !file.is_empty() => file,
+            _ => return None,
+        };
+        let mut bounds = lines.splitn(2, '-').map(str::parse::<usize>);
+        let first = match bounds.next() {
+            Some(Ok(first)) if first > 0 => first,
+            _ => return None,
+        };
+        let last = match bounds.next() {
+            Some(Ok(last)) if last >= first => last,
+            Some(_) => return None,
+            None => first,
+        };
+        Some(Self {
+            file: file.to_owned(),
+            first: first,
+            last: last,
+        })
+    }
+
+    /// Checks whether `line` of the file `file_name` is in this range.
+    pub fn contains(&self, file_name: &str, line: usize) -> bool {
+        file_name.ends_with(&self.file) && self.first <= line && line <= self.last
+    }
 }
 
 /// Search for the configuration file.
@@ -241,13 +326,17 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
             .is_empty()
     );
     match toml::from_str(&file) {
-        Ok(toml) => (
-            toml,
-            ERRORS
-                .lock()
-                .expect("no threading -> mutex always safe")
-                .split_off(0),
-        ),
+        Ok(toml) => {
+            let (mut conf, mut errors) = (
+                toml,
+                ERRORS
+                    .lock()
+                    .expect("no threading -> mutex always safe")
+                    .split_off(0),
+            );
+            validate(&mut conf, &mut errors);
+            (conf, errors)
+        },
         Err(e) => {
             let mut errors = ERRORS
                 .lock()
@@ -258,3 +347,33 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
         },
     }
 }
+
+/// The smallest size of a digit group the grouping lints work with.
+const MIN_GROUP_SIZE: u64 = 2;
+
+/// Checks the values that have the right type, but make no sense, and resets
+/// them to their defaults.
+fn validate(conf: &mut Conf, errors: &mut Vec<Error>) {
+    if conf.large_digit_group_threshold < MIN_GROUP_SIZE {
+        errors.push(Error::Invalid(
+            "large-digit-group-threshold",
+            format!(
+                "digit groups must be allowed at least {} digits, but the threshold is {}",
+                MIN_GROUP_SIZE,
+                conf.large_digit_group_threshold
+            ),
+        ));
+        conf.large_digit_group_threshold = default(Vec::new()).0.large_digit_group_threshold;
+    }
+    let invalid_line_range = conf.lintable_lines
+        .iter()
+        .find(|entry| LineRange::parse(entry).is_none())
+        .cloned();
+    if let Some(entry) = invalid_line_range {
+        errors.push(Error::Invalid(
+            "lintable-lines",
+            format!("expected `\"path:first-last\"` or `\"path:line\"`, but got `\"{}\"`", entry),
+        ));
+        conf.lintable_lines = Vec::new();
+    }
+}
@@ -5,7 +5,7 @@ use rustc::hir::def_id::{DefId, CRATE_DEF_INDEX};
 use rustc::hir::def::Def;
 use rustc::hir::intravisit::{NestedVisitorMap, Visitor};
 use rustc::hir::map::Node;
-use rustc::lint::{LateContext, Level, Lint, LintContext};
+use rustc::lint::{LateContext, Level, Lint, LintContext, LintSource};
 use rustc::session::Session;
 use rustc::traits;
 use rustc::ty::{self, Ty, TyCtxt};
@@ -612,6 +612,37 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
     db.docs_link(lint);
 }
 
+/// Like `span_lint_and_then`, but if `deny_default` is set, `lint` is denied
+/// at `node` instead of warning by default, as the `safety-critical`
+/// configuration asks. A level set by an attribute or on the command line is
+/// kept, and so is a `--cap-lints` below `deny`.
+pub fn span_lint_denying_default_and_then<'a, 'tcx: 'a, F>(
+    cx: &'a LateContext<'a, 'tcx>,
+    deny_default: bool,
+    lint: &'static Lint,
+    node: ast::NodeId,
+    sp: Span,
+    msg: &str,
+    f: F,
+) where
+    F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
+{
+    // The level returned is already capped, but raising it must be capped too.
+    let deny_capped = cx.sess().opts.lint_cap.map_or(false, |cap| cap < Level::Deny);
+    match cx.tcx.lint_level_at_node(lint, node) {
+        (Level::Warn, LintSource::Default) if deny_default && !deny_capped => {
+            let mut db = DiagnosticWrapper(cx.sess().struct_span_err(sp, msg));
+            db.0.note(&format!(
+                "#[deny({})] implied by `safety-critical = true` in the clippy configuration",
+                lint.name_lower()
+            ));
+            f(&mut db.0);
+            db.docs_link(lint);
+        },
+        _ => span_lint_and_then(cx, lint, sp, msg, f),
+    }
+}
+
 pub fn span_lint_and_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     cx: &'a T,
     lint: &'static Lint,
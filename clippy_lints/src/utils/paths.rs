@@ -23,8 +23,11 @@ pub const DEFAULT_TRAIT: [&'static str; 3] = ["core", "default", "Default"];
 pub const DISPLAY_FMT_METHOD: [&'static str; 4] = ["core", "fmt", "Display", "fmt"];
 pub const DOUBLE_ENDED_ITERATOR: [&'static str; 4] = ["core", "iter", "traits", "DoubleEndedIterator"];
 pub const DROP: [&'static str; 3] = ["core", "mem", "drop"];
+pub const DURATION_FROM_MILLIS: [&'static str; 5] = ["std", "time", "duration", "Duration", "from_millis"];
+pub const DURATION_FROM_SECS: [&'static str; 5] = ["std", "time", "duration", "Duration", "from_secs"];
 pub const FMT_ARGUMENTS_NEWV1: [&'static str; 4] = ["core", "fmt", "Arguments", "new_v1"];
 pub const FMT_ARGUMENTV1_NEW: [&'static str; 4] = ["core", "fmt", "ArgumentV1", "new"];
+pub const FROM_TRAIT: [&'static str; 3] = ["core", "convert", "From"];
 pub const HASH: [&'static str; 2] = ["hash", "Hash"];
 pub const HASHMAP: [&'static str; 5] = ["std", "collections", "hash", "map", "HashMap"];
 pub const HASHMAP_ENTRY: [&'static str; 5] = ["std", "collections", "hash", "map", "Entry"];
@@ -44,6 +47,7 @@ pub const OPS_MODULE: [&'static str; 2] = ["core", "ops"];
 pub const OPTION: [&'static str; 3] = ["core", "option", "Option"];
 pub const OPTION_NONE: [&'static str; 4] = ["core", "option", "Option", "None"];
 pub const OPTION_SOME: [&'static str; 4] = ["core", "option", "Option", "Some"];
+pub const PROCESS_EXIT: [&'static str; 3] = ["std", "process", "exit"];
 pub const PTR_NULL: [&'static str; 2] = ["ptr", "null"];
 pub const PTR_NULL_MUT: [&'static str; 2] = ["ptr", "null_mut"];
 pub const RANGE: [&'static str; 3] = ["core", "ops", "Range"];
@@ -74,6 +78,7 @@ pub const SERDE_DE_VISITOR: [&'static str; 3] = ["serde", "de", "Visitor"];
 pub const SLICE_INTO_VEC: [&'static str; 4] = ["alloc", "slice", "<impl [T]>", "into_vec"];
 pub const SLICE_ITER: [&str; 3] = ["core", "slice", "Iter"];
 pub const STRING: [&'static str; 3] = ["alloc", "string", "String"];
+pub const THREAD_SLEEP: [&'static str; 3] = ["std", "thread", "sleep"];
 pub const TO_OWNED: [&'static str; 3] = ["alloc", "borrow", "ToOwned"];
 pub const TO_STRING: [&'static str; 3] = ["alloc", "string", "ToString"];
 pub const TRANSMUTE: [&'static str; 4] = ["core", "intrinsics", "", "transmute"];
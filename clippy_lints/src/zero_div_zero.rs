@@ -1,7 +1,9 @@
 use consts::{constant_simple, Constant, FloatWidth};
 use rustc::lint::*;
 use rustc::hir::*;
-use utils::span_help_and_lint;
+use rustc::ty;
+use syntax::ast::FloatTy;
+use utils::{span_help_and_lint, span_lint_and_sugg};
 
 /// **What it does:** Checks for `0.0 / 0.0`.
 ///
@@ -20,17 +22,35 @@ declare_lint! {
     "usage of `0.0 / 0.0` to obtain NaN instead of std::f32::NaN or std::f64::NaN"
 }
 
+/// **What it does:** Checks for a nonzero float literal divided by `0.0`,
+/// e.g. `1.0 / 0.0`.
+///
+/// **Why is this bad?** It's less readable than `std::f32::INFINITY` or
+/// `std::f64::INFINITY`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// 1.0f64 / 0.0
+/// ```
+declare_lint! {
+    pub NONZERO_DIVIDED_BY_ZERO,
+    Warn,
+    "usage of `1.0 / 0.0` to obtain infinity instead of std::f32::INFINITY or std::f64::INFINITY"
+}
+
 pub struct Pass;
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(ZERO_DIVIDED_BY_ZERO)
+        lint_array!(ZERO_DIVIDED_BY_ZERO, NONZERO_DIVIDED_BY_ZERO)
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        // check for instances of 0.0/0.0
+        // check for instances of 0.0/0.0 and 1.0/0.0
         if_let_chain! {[
             let ExprBinary(ref op, ref left, ref right) = expr.node,
             let BinOp_::BiDiv = op.node,
@@ -39,7 +59,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             // do something like 0.0/(2.0 - 2.0), but it would be nice to warn on that case too.
             let Some(Constant::Float(ref lhs_value, lhs_width)) = constant_simple(cx, left),
             let Some(Constant::Float(ref rhs_value, rhs_width)) = constant_simple(cx, right),
-            Ok(0.0) == lhs_value.parse(),
+            let Ok(lhs) = lhs_value.parse::<f64>(),
             Ok(0.0) == rhs_value.parse()
         ], {
             // since we're about to suggest a use of std::f32::NaN or std::f64::NaN,
@@ -49,9 +69,27 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 | (_, FloatWidth::F64) => "f64",
                 _ => "f32"
             };
-            span_help_and_lint(cx, ZERO_DIVIDED_BY_ZERO, expr.span,
-                "constant division of 0.0 with 0.0 will always result in NaN",
-                &format!("Consider using `std::{}::NAN` if you would like a constant representing NaN", float_type));
+            if lhs == 0.0 {
+                span_help_and_lint(cx, ZERO_DIVIDED_BY_ZERO, expr.span,
+                    "constant division of 0.0 with 0.0 will always result in NaN",
+                    &format!("Consider using `std::{}::NAN` if you would like a constant representing NaN", float_type));
+            } else if lhs.is_finite() {
+                // Dividing by `-0.0` flips the sign, just like a negative dividend.
+                let infinity = if (lhs < 0.0) != rhs_value.starts_with('-') {
+                    "NEG_INFINITY"
+                } else {
+                    "INFINITY"
+                };
+                // Unlike the literals, the type of the division is never ambiguous.
+                let float_type = match cx.tables.expr_ty(expr).sty {
+                    ty::TyFloat(FloatTy::F32) => "f32",
+                    _ => "f64",
+                };
+                span_lint_and_sugg(cx, NONZERO_DIVIDED_BY_ZERO, expr.span,
+                    "constant division of a nonzero float by 0.0 will always result in infinity",
+                    "use the constant",
+                    format!("std::{}::{}", float_type, infinity));
+            }
         }}
     }
 }
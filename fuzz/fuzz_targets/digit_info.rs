@@ -0,0 +1,28 @@
+//! Feeds arbitrary strings, not just valid literals, to the literal parsing
+//! of the grouping lints, which must never panic.
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate clippy_lints;
+
+use clippy_lints::literal_digit_grouping::{suggest_grouping_for, DigitInfo};
+use std::str;
+
+fuzz_target!(|data: &[u8]| {
+    let (group_size, src) = match data.split_first() {
+        Some((&group_size, rest)) => (group_size as usize % 8, rest),
+        None => return,
+    };
+    if let Ok(src) = str::from_utf8(src) {
+        for &float in &[false, true] {
+            let digit_info = DigitInfo::new(src, float);
+            let _ = digit_info.split_exponent();
+            let _ = digit_info.grouping_hint();
+            let _ = digit_info.grouping_hint_by(group_size);
+            let _ = digit_info.normalized_hint();
+            let _ = digit_info.int_value();
+            let _ = DigitInfo::checked_new(src, float).map(|digit_info| digit_info.grouping_hint());
+        }
+        let _ = suggest_grouping_for(src);
+    }
+});
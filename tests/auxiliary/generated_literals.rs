@@ -0,0 +1,10 @@
+// @generated by a build script, do not edit.
+
+pub const TABLE: [u64; 2] = [61864918973511, 1_23_456];
+
+pub fn lookup(key: u64) -> bool {
+    match key {
+        61864918973511 | 1000000...1_23_4567 => true,
+        _ => false,
+    }
+}
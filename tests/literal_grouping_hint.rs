@@ -0,0 +1,721 @@
+#![feature(i128_type, rustc_private)]
+
+//! Checks the grouping suggestions of literals, and that they keep the value
+//! of arbitrary literals.
+
+extern crate clippy_lints;
+extern crate quickcheck;
+extern crate syntax;
+
+use clippy_lints::literal_digit_grouping::{ascii_separators, is_well_formed_hint, suggest_grouping_for, DigitInfo,
+                                           LiteralDigitGrouping, Radix};
+use clippy_lints::utils::conf;
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use syntax::codemap::FilePathMapping;
+use syntax::parse::{self, ParseSess};
+
+/// The source of a valid integral or floating-point literal.
+#[derive(Clone, Debug)]
+struct Literal {
+    src: String,
+    float: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    Int(u64),
+    Float(u64),
+}
+
+fn pick<G: Gen>(g: &mut G, n: usize) -> usize {
+    usize::arbitrary(g) % n
+}
+
+fn chance<G: Gen>(g: &mut G) -> bool {
+    bool::arbitrary(g)
+}
+
+/// Generates up to `max` digits out of `alphabet`, with random underscores
+/// after the first digit.
+fn gen_digits<G: Gen>(g: &mut G, alphabet: &[u8], max: usize) -> String {
+    let len = 1 + pick(g, max);
+    let mut digits = String::new();
+    for i in 0..len {
+        if i > 0 && pick(g, 4) == 0 {
+            digits.push('_');
+        }
+        digits.push(alphabet[pick(g, alphabet.len())] as char);
+    }
+    if chance(g) && pick(g, 4) == 0 {
+        digits.push('_');
+    }
+    digits
+}
+
+fn gen_suffix<G: Gen>(g: &mut G, suffixes: &[&str]) -> String {
+    if chance(g) {
+        return String::new();
+    }
+    let underscore = if chance(g) { "_" } else { "" };
+    format!("{}{}", underscore, suffixes[pick(g, suffixes.len())])
+}
+
+impl Arbitrary for Literal {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        const DEC: &[u8] = b"0123456789";
+        if pick(g, 3) == 0 {
+            // The mantissa is kept short, so that the value stays finite.
+            let mut src = gen_digits(g, DEC, 8);
+            if pick(g, 8) == 0 {
+                // `1.` is a float literal as well, but cannot take a suffix
+                src.push('.');
+                return Literal { src: src, float: true };
+            }
+            let has_frac = chance(g);
+            let has_exp = !has_frac || chance(g);
+            if has_frac {
+                src.push('.');
+                src.push_str(&gen_digits(g, DEC, 8));
+            }
+            if has_exp {
+                src.push(if chance(g) { 'e' } else { 'E' });
+                src.push_str(["", "+", "-"][pick(g, 3)]);
+                src.push_str(&gen_digits(g, DEC, 2));
+            }
+            src.push_str(&gen_suffix(g, &["f32", "f64"]));
+            Literal { src: src, float: true }
+        } else {
+            // The number of digits is chosen so that the value fits a `u64`.
+            let (prefix, alphabet, max): (&str, &[u8], usize) = match pick(g, 4) {
+                0 => ("0b", b"01", 64),
+                1 => ("0o", b"01234567", 21),
+                2 => ("", DEC, 19),
+                _ => ("0x", b"0123456789abcdefABCDEF", 16),
+            };
+            let mut src = prefix.to_owned();
+            if !prefix.is_empty() && pick(g, 8) == 0 {
+                src.push('_');
+            }
+            src.push_str(&gen_digits(g, alphabet, max));
+            src.push_str(&gen_suffix(g, &["u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize"]));
+            Literal { src: src, float: false }
+        }
+    }
+}
+
+macro_rules! try_opt {
+    ($e:expr) => {
+        match $e {
+            Some(v) => v,
+            None => return None,
+        }
+    };
+}
+
+/// Checks that `digits` consist of digits of `radix` and underscores, and
+/// returns them without the underscores.
+fn strip_digits(digits: &str, radix: u32, leading_underscore: bool) -> Option<String> {
+    if (!leading_underscore && digits.starts_with('_')) || !digits.chars().all(|c| c == '_' || c.is_digit(radix)) {
+        return None;
+    }
+    let stripped = digits.chars().filter(|&c| c != '_').collect::<String>();
+    if stripped.is_empty() {
+        None
+    } else {
+        Some(stripped)
+    }
+}
+
+/// Parses a literal the way the lexer does and returns its value, or `None`
+/// if `src` is not a valid literal.
+fn parse(src: &str, float: bool) -> Option<Value> {
+    if float {
+        let (mantissa, suffix) = match src.find('f') {
+            Some(idx) => src.split_at(idx),
+            None => (src, ""),
+        };
+        if !suffix.is_empty() && suffix != "f32" && suffix != "f64" {
+            return None;
+        }
+        let (mantissa, exponent) = match mantissa.find(|c| c == 'e' || c == 'E') {
+            Some(idx) => (&mantissa[..idx], Some(&mantissa[idx + 1..])),
+            None => (mantissa, None),
+        };
+        let mut parts = mantissa.splitn(2, '.');
+        let mut value = try_opt!(strip_digits(parts.next().expect("splitn returns at least one element"), 10, false));
+        if let Some(frac) = parts.next() {
+            value.push('.');
+            if frac.is_empty() {
+                if exponent.is_some() || !suffix.is_empty() {
+                    return None;
+                }
+            } else {
+                value.push_str(&try_opt!(strip_digits(frac, 10, false)));
+            }
+        }
+        if let Some(exponent) = exponent {
+            let (sign, exponent) = if exponent.starts_with('+') || exponent.starts_with('-') {
+                exponent.split_at(1)
+            } else {
+                ("", exponent)
+            };
+            value.push('e');
+            value.push_str(sign);
+            value.push_str(&try_opt!(strip_digits(exponent, 10, true)));
+        }
+        value.parse::<f64>().ok().map(|f| Value::Float(f.to_bits()))
+    } else {
+        let (radix, digits) = if src.starts_with("0x") {
+            (16, &src[2..])
+        } else if src.starts_with("0o") {
+            (8, &src[2..])
+        } else if src.starts_with("0b") {
+            (2, &src[2..])
+        } else {
+            (10, src)
+        };
+        let (digits, suffix) = match digits.find(|c| c == 'i' || c == 'u') {
+            Some(idx) => digits.split_at(idx),
+            None => (digits, ""),
+        };
+        let suffixes = ["", "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize"];
+        if !suffixes.contains(&suffix) {
+            return None;
+        }
+        let digits = try_opt!(strip_digits(digits, radix, radix != 10));
+        u64::from_str_radix(&digits, radix).ok().map(Value::Int)
+    }
+}
+
+/// Runs the grouping lints with the default configuration over `src`, and
+/// returns each replaced literal with its replacement.
+fn suggestions(src: &str) -> Vec<(String, String)> {
+    let sess = ParseSess::new(FilePathMapping::empty());
+    let krate = match parse::parse_crate_from_source_str("test.rs".to_owned(), src.to_owned(), &sess) {
+        Ok(krate) => krate,
+        Err(mut err) => {
+            err.emit();
+            panic!("the test source doesn't parse");
+        },
+    };
+    let mut pass = LiteralDigitGrouping::new(&conf::read(None).0);
+    pass.grouping_suggestions(&krate, sess.codemap())
+        .into_iter()
+        .map(|(span, hint)| {
+            let lit = sess.codemap().span_to_snippet(span).expect("suggestions are for literals in `src`");
+            (lit, hint)
+        })
+        .collect()
+}
+
+/// Like `suggestions`, for the literals `lits` in a tuple expression.
+fn tuple_suggestions(lits: &[&str]) -> Vec<(String, String)> {
+    suggestions(&format!("fn f() {{ let _ = ({}); }}", lits.join(", ")))
+}
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|&(lit, hint)| (lit.to_owned(), hint.to_owned())).collect()
+}
+
+#[test]
+fn test_suggestions_with_crlf() {
+    let src = concat!(
+        "fn f() {\r\n",
+        "    let _ = 12345678;\r\n",
+        "    let _ = (0x1_23_4567,\r\n",
+        "             1234.56789_f64);\r\n",
+        "}\r\n"
+    );
+    assert_eq!(
+        suggestions(src),
+        pairs(&[
+            ("12345678", "12_345_678"),
+            ("0x1_23_4567", "0x123_4567"),
+            ("1234.56789_f64", "1_234.567_89_f64"),
+        ])
+    );
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("1_000_u32", false), Some(Value::Int(1000)));
+    assert_eq!(parse("0x_ff", false), Some(Value::Int(255)));
+    assert_eq!(parse("_1", false), None);
+    assert_eq!(parse("1.5e_-3", true), None);
+    assert_eq!(parse("1.5e-_3", true), Some(Value::Float(1.5e-3f64.to_bits())));
+    assert_eq!(parse("1.", true), Some(Value::Float(1f64.to_bits())));
+    assert_eq!(parse("1.f32", true), None);
+}
+
+#[test]
+fn test_grouping_hint_keeps_value() {
+    fn prop(lit: Literal) -> bool {
+        let hint = DigitInfo::new(&lit.src, lit.float).grouping_hint();
+        let value = parse(&lit.src, lit.float);
+        value.is_some() && value == parse(&hint, lit.float)
+    }
+    quickcheck(prop as fn(Literal) -> bool);
+}
+
+#[test]
+fn test_is_well_formed_hint() {
+    assert!(is_well_formed_hint("1_000", false));
+    assert!(is_well_formed_hint("0b1010_1010_u8", false));
+    assert!(is_well_formed_hint("0.123_456_7", true));
+    assert!(is_well_formed_hint("1.5e-3_f32", true));
+    assert!(is_well_formed_hint("1.", true));
+    assert!(!is_well_formed_hint("1__000", false));
+    assert!(!is_well_formed_hint("_1000", false));
+    assert!(!is_well_formed_hint("1000_", false));
+    assert!(!is_well_formed_hint(".5", true));
+    assert!(!is_well_formed_hint("1_.5", true));
+    assert!(!is_well_formed_hint("1._5", true));
+    assert!(!is_well_formed_hint("0b102", false));
+}
+
+#[test]
+fn test_grouping_hint_is_well_formed() {
+    fn prop(lit: Literal) -> bool {
+        is_well_formed_hint(&DigitInfo::new(&lit.src, lit.float).grouping_hint(), lit.float)
+    }
+    quickcheck(prop as fn(Literal) -> bool);
+}
+
+#[test]
+fn test_grouping_hint_exponent() {
+    assert_eq!(DigitInfo::new("123456.78e-9", true).grouping_hint(), "123_456.78e-9");
+    assert_eq!(DigitInfo::new("55.55e-6_f64", true).grouping_hint(), "55.55e-6_f64");
+    assert_eq!(DigitInfo::new("1234e100", true).grouping_hint(), "1_234e100");
+    assert_eq!(
+        tuple_suggestions(&["123456.78e-9", "55.55e-6_f64", "1_23_456.5e1"]),
+        pairs(&[("123456.78e-9", "123_456.78e-9"), ("1_23_456.5e1", "123_456.5e1")])
+    );
+}
+
+#[test]
+fn test_float_grouping_consistency() {
+    // Group sizes count digits only, whichever part of the float is longer.
+    assert_eq!(
+        tuple_suggestions(&["1_000.50", "1000.5_00", "1_00.5", "1.000_5", "1_000.123_4"]),
+        pairs(&[("1000.5_00", "1_000.500")])
+    );
+}
+
+#[test]
+fn test_grouping_hint_stops_at_exponent() {
+    assert_eq!(DigitInfo::new("1.5e10", true).grouping_hint(), "1.5e10");
+    assert_eq!(DigitInfo::new("1.5E123456", true).grouping_hint(), "1.5E123456");
+    assert_eq!(DigitInfo::new("12345.67890e10", true).grouping_hint(), "12_345.678_90e10");
+    assert_eq!(DigitInfo::new("12345.67890e10", true).split_exponent(), ("12345.67890", "e10"));
+    assert_eq!(
+        tuple_suggestions(&["1.5e10", "1.5E123456", "12345.67890e10"]),
+        pairs(&[("12345.67890e10", "12_345.678_90e10")])
+    );
+}
+
+#[test]
+fn test_grouping_hint_suffixed_float() {
+    assert_eq!(DigitInfo::new("1_000_000.123_456f64", true).grouping_hint(), "1_000_000.123_456f64");
+    assert_eq!(DigitInfo::new("1000000.123456f64", true).grouping_hint(), "1_000_000.123_456f64");
+    assert_eq!(DigitInfo::new("1000000.123456_f32", true).grouping_hint(), "1_000_000.123_456_f32");
+    assert_eq!(DigitInfo::new("12345.6789e10_f64", true).grouping_hint(), "12_345.678_9e10_f64");
+    assert_eq!(DigitInfo::new("123456f64", true).grouping_hint(), "123_456f64");
+    assert_eq!(DigitInfo::new("1_000_000.123_456f64", true).suffix, Some("f64"));
+    assert_eq!(
+        tuple_suggestions(&["1_000_000.123_456f64", "1000000.123456_f64", "1_000000.5f32", "12345.6789e10_f64"]),
+        pairs(&[
+            ("1000000.123456_f64", "1_000_000.123_456_f64"),
+            ("1_000000.5f32", "1_000_000.5f32"),
+            ("12345.6789e10_f64", "12_345.678_9e10_f64"),
+        ])
+    );
+}
+
+#[test]
+fn test_normalized_hint() {
+    assert_eq!(DigitInfo::new("0xabcdEF12_u32", false).normalized_hint(), "0xABCD_EF12_u32");
+    assert_eq!(DigitInfo::new("0xabcdef12", false).normalized_hint(), "0xabcd_ef12");
+    assert_eq!(DigitInfo::new("12345678", false).normalized_hint(), "12_345_678");
+    assert_eq!(
+        tuple_suggestions(&["0xabcdEF12_u32", "0xabcd_ef12", "12345678"]),
+        pairs(&[("0xabcdEF12_u32", "0xABCD_EF12_u32"), ("12345678", "12_345_678")])
+    );
+}
+
+#[test]
+fn test_grouping_hint_after_prefix() {
+    assert_eq!(DigitInfo::new("0xFFFF", false).grouping_hint(), "0xFFFF");
+    assert_eq!(DigitInfo::new("0xFFFFF", false).grouping_hint(), "0xF_FFFF");
+    assert_eq!(DigitInfo::new("0x1_0000", false).grouping_hint(), "0x1_0000");
+    assert_eq!(DigitInfo::new("0x_FFFF_FFFF", false).grouping_hint(), "0xFFFF_FFFF");
+    assert_eq!(DigitInfo::new("0b1111_0000_u8", false).grouping_hint(), "0b1111_0000_u8");
+    assert_eq!(DigitInfo::new("0o777", false).grouping_hint(), "0o777");
+    assert_eq!(
+        tuple_suggestions(&["0xFFFF", "0xFFFFF", "0x1_0000", "0x_FFFF_FFFF", "0b1111_0000_u8"]),
+        pairs(&[("0xFFFFF", "0xF_FFFF")])
+    );
+}
+
+#[test]
+fn test_octal_grouped_by_three() {
+    assert_eq!(Radix::Octal.suggest_grouping(), 3);
+    assert_eq!(DigitInfo::new("0o17777", false).grouping_hint(), "0o17_777");
+    assert_eq!(
+        tuple_suggestions(&["0o17777", "0o1_7777_77"]),
+        pairs(&[("0o17777", "0o17_777"), ("0o1_7777_77", "0o1_777_777")])
+    );
+    // The suggestions are grouped like the lints want them.
+    assert_eq!(tuple_suggestions(&["0o17_777", "0o17_777_777"]), vec![]);
+}
+
+#[test]
+fn test_hex_groups_of_one() {
+    assert_eq!(
+        tuple_suggestions(&["0xFF_F", "0xF_FF", "0xFFF_F"]),
+        pairs(&[("0xFF_F", "0xFFF"), ("0xFFF_F", "0xFFFF")])
+    );
+}
+
+#[test]
+fn test_grouping_hint_lowercases_prefix() {
+    assert_eq!(DigitInfo::new("0XABCDEF", false).grouping_hint(), "0xAB_CDEF");
+    assert_eq!(DigitInfo::new("0B11110000_u8", false).grouping_hint(), "0b1111_0000_u8");
+    assert_eq!(DigitInfo::new("0O777", false).grouping_hint(), "0o777");
+    assert_eq!(suggest_grouping_for("0XABCDEF"), Some("0xAB_CDEF".to_owned()));
+}
+
+#[test]
+fn test_grouping_hint_more_underscores_than_digits() {
+    let digit_info = DigitInfo::new("__1", false);
+    assert_eq!(digit_info.digits, "__1");
+    assert!(digit_info.has_valid_digits());
+    assert_eq!(digit_info.grouping_hint(), "1");
+    assert_eq!(DigitInfo::new("1____", false).grouping_hint(), "1");
+    assert_eq!(DigitInfo::new("1_2_3_4_5", false).grouping_hint(), "12_345");
+    assert_eq!(
+        tuple_suggestions(&["1____", "1_2_3_4_5"]),
+        pairs(&[("1_2_3_4_5", "12_345")])
+    );
+    // `__1` is an identifier in Rust source, not a literal.
+    assert!(DigitInfo::checked_new("__1", false).is_none());
+}
+
+#[test]
+fn test_from_value() {
+    let mut buf = String::new();
+    assert_eq!(DigitInfo::from_value(1_048_576, Radix::Hexadecimal, None, &mut buf).grouping_hint(), "0x10_0000");
+    assert_eq!(DigitInfo::from_value(16, Radix::Binary, None, &mut buf).grouping_hint(), "0b1_0000");
+    assert_eq!(DigitInfo::from_value(1_000_000, Radix::Decimal, Some("u32"), &mut buf).grouping_hint(), "1_000_000u32");
+    let digit_info = DigitInfo::from_value(0o755, Radix::Octal, None, &mut buf);
+    assert_eq!(digit_info.int_value(), Some(0o755));
+    assert_eq!(digit_info.grouping_hint(), "0o755");
+}
+
+#[test]
+fn test_radix_is_valid_digit() {
+    assert!(Radix::Binary.is_valid_digit('0') && Radix::Binary.is_valid_digit('1'));
+    assert!(!Radix::Binary.is_valid_digit('2'));
+    assert!(Radix::Octal.is_valid_digit('7'));
+    assert!(!Radix::Octal.is_valid_digit('8'));
+    assert!(Radix::Decimal.is_valid_digit('9'));
+    assert!(!Radix::Decimal.is_valid_digit('a'));
+    assert!("0123456789abcdefABCDEF".chars().all(|c| Radix::Hexadecimal.is_valid_digit(c)));
+    assert!(!Radix::Hexadecimal.is_valid_digit('g') && !Radix::Hexadecimal.is_valid_digit('G'));
+    for radix in &[Radix::Binary, Radix::Octal, Radix::Decimal, Radix::Hexadecimal] {
+        assert!(!radix.is_valid_digit('_'));
+        assert!(!radix.is_valid_digit('.'));
+        assert!(!radix.is_valid_digit('\u{ff11}'));
+    }
+    // The decimal point is only taken for floats, besides the digits.
+    assert!(DigitInfo::new("1.5", true).has_valid_digits());
+    assert!(!DigitInfo::new("1.5", false).has_valid_digits());
+}
+
+#[test]
+fn test_missing_integral_part() {
+    assert!(DigitInfo::checked_new(".5", true).is_some());
+    assert!(DigitInfo::checked_new(".5f64", true).is_some());
+    assert!(DigitInfo::checked_new(".5", false).is_none());
+    assert!(DigitInfo::checked_new(".e5", true).is_none());
+    assert!(DigitInfo::checked_new(".", true).is_none());
+    assert_eq!(DigitInfo::new(".5f64", true).suffix, Some("f64"));
+    assert_eq!(suggest_grouping_for(".1234567"), Some("0.123_456_7".to_owned()));
+    assert_eq!(suggest_grouping_for(".5"), Some("0.5".to_owned()));
+}
+
+#[test]
+fn test_checked_new() {
+    assert!(DigitInfo::checked_new("1_000", false).is_some());
+    assert!(DigitInfo::checked_new("0xDEAD_beef_u32", false).is_some());
+    assert!(DigitInfo::checked_new("1.5e-3_f32", true).is_some());
+    assert!(DigitInfo::checked_new("12345\u{ff16}", false).is_none());
+    assert!(DigitInfo::checked_new("1.5\u{b2}", true).is_none());
+    assert!(DigitInfo::checked_new("0b102", false).is_none());
+    assert!(DigitInfo::checked_new("foo", false).is_none());
+    // a stray character doesn't make the unchecked version panic
+    let _ = DigitInfo::new("12\u{ff13}45", false).grouping_hint();
+}
+
+#[test]
+fn test_suggest_grouping_for_integer_suffixes() {
+    assert_eq!(suggest_grouping_for("1000000usize"), Some("1_000_000usize".to_owned()));
+    assert_eq!(suggest_grouping_for("1000000isize"), Some("1_000_000isize".to_owned()));
+    assert_eq!(suggest_grouping_for("1000000_usize"), Some("1_000_000_usize".to_owned()));
+    assert_eq!(suggest_grouping_for("0xdeadbeefusize"), Some("0xdead_beefusize".to_owned()));
+    assert_eq!(suggest_grouping_for("1000000u64"), Some("1_000_000u64".to_owned()));
+    assert_eq!(suggest_grouping_for("1234567.5f64"), Some("1_234_567.5f64".to_owned()));
+}
+
+#[test]
+fn test_hex_digits_are_not_suffixes() {
+    for &(src, value) in &[("0x1e", 0x1e), ("0x1f", 0x1f), ("0xabcdef", 0xabcdef), ("0xf16", 0xf16)] {
+        let digit_info = DigitInfo::new(src, false);
+        assert_eq!(digit_info.digits, &src[2..]);
+        assert_eq!(digit_info.suffix, None);
+        assert_eq!(digit_info.int_value(), Some(value));
+    }
+    assert_eq!(DigitInfo::new("0x1f_u8", false).suffix, Some("_u8"));
+    assert_eq!(suggest_grouping_for("0x1e"), None);
+    assert_eq!(suggest_grouping_for("0x1f"), None);
+    assert_eq!(suggest_grouping_for("0xabcdef"), Some("0xab_cdef".to_owned()));
+    assert_eq!(
+        tuple_suggestions(&["0x1e", "0x1f", "0xabcdef", "0xabcdef_u32"]),
+        pairs(&[("0xabcdef", "0xab_cdef"), ("0xabcdef_u32", "0xab_cdef_u32")])
+    );
+}
+
+#[test]
+fn test_suffix_after_prefix() {
+    for &(src, digits, prefix, suffix) in &[
+        ("0b1010u8", "1010", "0b", "u8"),
+        ("0o17i32", "17", "0o", "i32"),
+        ("0x1Fu16", "1F", "0x", "u16"),
+        ("0b_1010_usize", "_1010", "0b", "_usize"),
+    ] {
+        let digit_info = DigitInfo::new(src, false);
+        assert_eq!(digit_info.digits, digits);
+        assert_eq!(digit_info.prefix, Some(prefix));
+        assert_eq!(digit_info.suffix, Some(suffix));
+    }
+}
+
+#[test]
+fn test_longest_suffixes() {
+    for &(src, digits, suffix, hint) in &[
+        ("1_000_usize", "1_000", "_usize", "1_000_usize"),
+        ("1_000_isize", "1_000", "_isize", "1_000_isize"),
+        ("1_000usize", "1_000", "usize", "1_000usize"),
+        ("1000000_isize", "1000000", "_isize", "1_000_000_isize"),
+        ("1__usize", "1_", "_usize", "1_usize"),
+    ] {
+        let digit_info = DigitInfo::new(src, false);
+        assert_eq!(digit_info.digits, digits);
+        assert_eq!(digit_info.prefix, None);
+        assert_eq!(digit_info.suffix, Some(suffix));
+        assert_eq!(digit_info.grouping_hint(), hint);
+    }
+    assert_eq!(
+        tuple_suggestions(&["1_000_usize", "1000000_isize", "1__usize"]),
+        pairs(&[("1000000_isize", "1_000_000_isize")])
+    );
+}
+
+/// Inserts an underscore between every `size` digits, counting from the
+/// left, after removing the existing underscores.
+fn reference_chunks(digits: &str, size: usize) -> String {
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().filter(|&c| c != '_').enumerate() {
+        if i > 0 && i % size == 0 {
+            grouped.push('_');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+fn reverse(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+/// A deliberately naive grouping: integral digits are grouped from the right
+/// by reversing them, fractional digits from the left, and everything else is
+/// kept as written.
+fn reference_hint(src: &str, float: bool) -> String {
+    let (prefix, size) = match src.get(..2) {
+        Some("0x") => ("0x", 4),
+        Some("0b") => ("0b", 4),
+        Some("0o") => ("0o", 3),
+        _ => ("", 3),
+    };
+    let body = &src[prefix.len()..];
+    let mut suffix_start = body
+        .find(|c| if float { c == 'f' } else { c == 'i' || c == 'u' })
+        .unwrap_or_else(|| body.len());
+    if body[..suffix_start].ends_with('_') {
+        suffix_start -= 1;
+    }
+    let (body, suffix) = body.split_at(suffix_start);
+    let exponent_start = if float {
+        body.find(|c| c == 'e' || c == 'E').unwrap_or_else(|| body.len())
+    } else {
+        body.len()
+    };
+    let (mantissa, exponent) = body.split_at(exponent_start);
+    let mut parts = mantissa.splitn(2, '.');
+    let int_part = parts.next().expect("splitn returns at least one element");
+    let mut hint = prefix.to_owned();
+    hint.push_str(&reverse(&reference_chunks(&reverse(int_part), size)));
+    if let Some(frac) = parts.next() {
+        hint.push('.');
+        hint.push_str(&reference_chunks(frac, size));
+    }
+    hint.push_str(exponent);
+    hint.push_str(suffix);
+    hint
+}
+
+#[test]
+fn test_grouping_hint_reference() {
+    let cases = [
+        ("1", false, "1"),
+        ("12", false, "12"),
+        ("123", false, "123"),
+        ("1234", false, "1_234"),
+        ("12345", false, "12_345"),
+        ("123456", false, "123_456"),
+        ("1234567", false, "1_234_567"),
+        ("1_2_3_4_5_6_7", false, "1_234_567"),
+        ("1234567890_u64", false, "1_234_567_890_u64"),
+        ("1234567890u64", false, "1_234_567_890u64"),
+        ("12345_i32", false, "12_345_i32"),
+        ("0b1", false, "0b1"),
+        ("0b10101", false, "0b1_0101"),
+        ("0b1010_1010_1010", false, "0b1010_1010_1010"),
+        ("0b11111111u8", false, "0b1111_1111u8"),
+        ("0o7", false, "0o7"),
+        ("0o1234567", false, "0o1_234_567"),
+        ("0o1234567_u32", false, "0o1_234_567_u32"),
+        ("0xF", false, "0xF"),
+        ("0xFFFFF", false, "0xF_FFFF"),
+        ("0x_DEAD_BEEF", false, "0xDEAD_BEEF"),
+        ("0xDEADBEEFu32", false, "0xDEAD_BEEFu32"),
+        ("0x1234567890abcdef_u64", false, "0x1234_5678_90ab_cdef_u64"),
+        ("1.", true, "1."),
+        ("1.5", true, "1.5"),
+        ("1234.5678", true, "1_234.567_8"),
+        ("1234567.1234567", true, "1_234_567.123_456_7"),
+        ("0.1234", true, "0.123_4"),
+        ("12345.678_9_f64", true, "12_345.678_9_f64"),
+        ("1e10", true, "1e10"),
+        ("123456e-7", true, "123_456e-7"),
+        ("1234.5e+3_f32", true, "1_234.5e+3_f32"),
+        ("1_23.45_6e1_0", true, "123.456e1_0"),
+    ];
+    for &(src, float, expected) in &cases {
+        assert_eq!(reference_hint(src, float), expected, "reference for {}", src);
+        assert_eq!(DigitInfo::new(src, float).grouping_hint(), expected, "grouping_hint for {}", src);
+    }
+}
+
+#[test]
+fn test_grouping_hint_full_width_binary() {
+    assert_eq!(DigitInfo::new("0b1111111111111111", false).grouping_hint(), "0b1111_1111_1111_1111");
+    assert_eq!(DigitInfo::new("0b1111_1111_1111_1111", false).grouping_hint(), "0b1111_1111_1111_1111");
+    assert_eq!(
+        DigitInfo::new("0b10000000000000000000000000000001_u32", false).grouping_hint(),
+        "0b1000_0000_0000_0000_0000_0000_0000_0001_u32"
+    );
+    assert_eq!(
+        DigitInfo::new("0b1010_1010_1010_1010_1010_1010_1010_1010", false).grouping_hint(),
+        "0b1010_1010_1010_1010_1010_1010_1010_1010"
+    );
+}
+
+#[test]
+fn test_grouping_hint_by() {
+    assert_eq!(DigitInfo::new("1234_5678.1234_5678", true).grouping_hint_by(4), "1234_5678.1234_5678");
+    assert_eq!(DigitInfo::new("1_234.5678", true).grouping_hint_by(4), "1234.5678");
+    assert_eq!(DigitInfo::new("12345678", false).grouping_hint_by(2), "12_34_56_78");
+    assert_eq!(DigitInfo::new("1_234.567_8e10_f64", true).grouping_hint_by(3), "1_234.567_8e10_f64");
+    assert_eq!(DigitInfo::new("0x1_0000_u32", false).grouping_hint_by(0), "0x10000_u32");
+}
+
+#[test]
+fn test_grouping_hint_dirty_input() {
+    for &(src, float) in &[
+        ("-1234567", false),
+        ("(1234567)", false),
+        ("1234567)", false),
+        ("0x(abcDEF12)", false),
+        ("-1234.5678", true),
+        ("(1234.5678_f64)", true),
+    ] {
+        let digit_info = DigitInfo::new(src, float);
+        assert!(!digit_info.has_valid_digits(), "{}", src);
+        assert_eq!(digit_info.grouping_hint(), src);
+        assert_eq!(digit_info.grouping_hint_by(2), src);
+        assert_eq!(digit_info.normalized_hint(), src);
+        assert!(DigitInfo::checked_new(src, float).is_none(), "{}", src);
+    }
+    assert!(DigitInfo::new("0x_abcd_EF12_u32", false).has_valid_digits());
+    assert!(DigitInfo::new("1_234.5e-6_f32", true).has_valid_digits());
+}
+
+#[test]
+fn test_grouping_hint_with() {
+    assert_eq!(DigitInfo::new("1234567", false).grouping_hint_with(3, ','), "1,234,567");
+    assert_eq!(DigitInfo::new("1_234_567", false).grouping_hint_with(3, ' '), "1 234 567");
+    assert_eq!(DigitInfo::new("1234567.891", true).grouping_hint_with(3, ','), "1,234,567.891");
+    assert_eq!(DigitInfo::new("12345.6789e10", true).grouping_hint_with(3, ','), "12,345.678,9e10");
+    assert_eq!(DigitInfo::new("0xDEADBEEF", false).grouping_hint_with(4, ' '), "0xDEAD BEEF");
+    assert_eq!(DigitInfo::new("1234567", false).grouping_hint_with(0, ','), "1234567");
+    assert_eq!(
+        DigitInfo::new("1234567", false).grouping_hint_with(3, '_'),
+        DigitInfo::new("1234567", false).grouping_hint()
+    );
+}
+
+#[test]
+fn test_arbitrary_input_does_not_panic() {
+    fn prop(src: String, float: bool, group_size: u8) -> bool {
+        let digit_info = DigitInfo::new(&src, float);
+        let _ = digit_info.grouping_hint_by(group_size as usize);
+        let _ = digit_info.normalized_hint();
+        let _ = digit_info.int_value();
+        let _ = suggest_grouping_for(&src);
+        true
+    }
+    quickcheck(prop as fn(String, bool, u8) -> bool);
+}
+
+#[test]
+fn test_suffix_without_digits() {
+    for &(src, suffix) in &[("0xu8", "u8"), ("0x_u8", "_u8"), ("_u8", "_u8"), ("0b_i32", "_i32")] {
+        let digit_info = DigitInfo::new(src, false);
+        assert_eq!(digit_info.digits, "", "{}", src);
+        assert_eq!(digit_info.suffix, Some(suffix), "{}", src);
+        assert_eq!(digit_info.int_value(), None, "{}", src);
+        let _ = digit_info.grouping_hint();
+        assert!(DigitInfo::checked_new(src, false).is_none(), "{}", src);
+    }
+    assert!(DigitInfo::checked_new("_f32", true).is_none());
+}
+
+#[test]
+fn test_suggested_group_sizes() {
+    for radix in &[Radix::Binary, Radix::Octal, Radix::Decimal, Radix::Hexadecimal] {
+        assert!(radix.suggest_grouping() >= 2, "{:?}", radix);
+    }
+}
+
+#[test]
+fn test_unicode_separators() {
+    assert_eq!(ascii_separators("1\u{2019}000\u{2019}000"), Some("1_000_000".to_owned()));
+    assert_eq!(ascii_separators("0xFF\u{2009}FF"), Some("0xFF_FF".to_owned()));
+    assert_eq!(ascii_separators("1\u{a0}000.5"), Some("1_000.5".to_owned()));
+    // Only separators between two digits are replaced.
+    assert_eq!(ascii_separators("\u{2019}1000\u{2019}"), None);
+    assert_eq!(ascii_separators("1000"), None);
+    assert_eq!(suggest_grouping_for("1\u{2019}000\u{2019}000"), Some("1_000_000".to_owned()));
+    assert_eq!(suggest_grouping_for("1234\u{ff3f}5678"), Some("12_345_678".to_owned()));
+}
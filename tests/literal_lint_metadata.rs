@@ -0,0 +1,40 @@
+#![feature(rustc_private)]
+
+extern crate clippy_lints;
+extern crate rustc;
+
+use clippy_lints::literal_digit_grouping::LiteralDigitGrouping;
+use clippy_lints::utils::conf;
+use rustc::lint::LintPass;
+
+#[test]
+fn test_grouping_lint_metadata() {
+    let pass = LiteralDigitGrouping::new(&conf::read(None).0);
+    let mut names: Vec<_> = pass.lint_metadata().iter().map(|metadata| metadata.name.to_string()).collect();
+    let mut declared: Vec<_> = pass.get_lints().iter().map(|lint| lint.name_lower()).collect();
+    names.sort();
+    declared.sort();
+    assert_eq!(names, declared);
+    for metadata in pass.lint_metadata() {
+        assert!(metadata.groups.contains(&"clippy_literal_representation"), "{}", metadata.name);
+    }
+}
+
+#[test]
+fn test_grouping_lint_metadata_styles() {
+    let pass = LiteralDigitGrouping::new(&conf::read(None).0);
+    let style: Vec<_> = pass.lint_metadata()
+        .iter()
+        .filter(|metadata| metadata.groups.contains(&"clippy_style"))
+        .map(|metadata| (metadata.name, metadata.version))
+        .collect();
+    assert_eq!(
+        style,
+        [
+            ("unreadable_literal", "0.0.148"),
+            ("inconsistent_digit_grouping", "0.0.148"),
+            ("large_digit_groups", "0.0.148"),
+            ("separator_before_decimal_point", "0.0.157"),
+        ]
+    );
+}
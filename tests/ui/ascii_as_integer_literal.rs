@@ -0,0 +1,18 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(ascii_as_integer_literal)]
+#![allow(unused_variables, unused_assignments)]
+
+fn main() {
+    let c: u8 = 65;
+    let quote: u8 = 39;
+    let hex: u8 = 0x41;
+    let wide: u32 = 65;
+    let newline: u8 = 10;
+
+    let n: u8 = 65;
+    let m = n + 1;
+
+    let mut k: u8 = 66;
+    k <<= 1;
+}
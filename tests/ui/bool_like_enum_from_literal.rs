@@ -0,0 +1,40 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(bool_like_enum_from_literal)]
+#![allow(unused_variables, dead_code)]
+
+enum Switch { Off, On }
+
+impl From<u8> for Switch {
+    fn from(n: u8) -> Self {
+        if n == 0 { Switch::Off } else { Switch::On }
+    }
+}
+
+enum Color { Red, Green, Blue }
+
+impl From<u8> for Color {
+    fn from(n: u8) -> Self {
+        match n {
+            0 => Color::Red,
+            1 => Color::Green,
+            _ => Color::Blue,
+        }
+    }
+}
+
+enum Level { Low, High }
+
+impl Level {
+    fn from(n: u8) -> Self {
+        if n == 0 { Level::Low } else { Level::High }
+    }
+}
+
+fn main() {
+    let on = Switch::from(1);
+    let off = Switch::from(0);
+    let other = Switch::from(2);
+    let green = Color::from(1);
+    let high = Level::from(1);
+}
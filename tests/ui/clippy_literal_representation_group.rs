@@ -0,0 +1,19 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![allow(unused_variables, dead_code)]
+
+#[allow(clippy_literal_representation)]
+fn allowed() {
+    let literals = (12345678, 1_23_456, 1_23456, 2.5e0);
+}
+
+fn warned() {
+    let literals = (12345678, 1_23_456, 1_23456, 2.5e0);
+}
+
+#[warn(clippy_literal_representation)]
+fn enabled() {
+    let padded = 0100.5;
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![allow(unused_variables, dead_code)]
+
+#[allow(clippy_style)]
+fn allowed() {
+    let literals = (12345678, 1_23_456, 1_23456);
+}
+
+fn warned() {
+    let literals = (12345678, 1_23_456, 1_23456);
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(comma_decimal_separator)]
+
+fn main() {
+    let _ = "1,5".parse::<f64>();
+    let _: Result<f32, _> = "0,25".parse();
+    let _ = "1.5".parse::<f64>();
+    let _ = "1,5".parse::<u32>();
+    let _ = "1,".parse::<f64>();
+    let _ = "1,0,5".parse::<f64>();
+}
@@ -0,0 +1,12 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_ignore_values.toml"))]
+#![warn(unreadable_literal, inconsistent_digit_grouping)]
+#![allow(unused_variables)]
+
+fn main() {
+    let hex: u32 = 0xDEADBEEF;
+    let decimal: u32 = 3735928559;
+    let binary: u32 = 0b11011110101011011011111011101111;
+    let regrouped: u32 = 0xDE_ADBEEF;
+    let other: u32 = 0xDEADBEEE;
+}
@@ -0,0 +1,6 @@
+// error-pattern: error reading Clippy's configuration file: invalid value for `large-digit-group-threshold`
+
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_large_digit_group_threshold_zero.toml"))]
+
+fn main() {}
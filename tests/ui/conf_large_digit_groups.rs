@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_large_digit_groups.toml"))]
+#[warn(large_digit_groups)]
+#[allow(unused_variables)]
+fn main() {
+    let good = (1_23456, 0x1_23456_78901_usize, 1_23456.12_f32);
+    let bad = (1_2345678, 0x1_2345678_u64);
+}
@@ -0,0 +1,14 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_lintable_lines.toml"))]
+#![warn(unreadable_literal, inconsistent_digit_grouping)]
+#![allow(unused_variables)]
+
+fn main() {
+    // only lines 9, 10 and 13 are linted
+    let before = 61864918973511u64;
+    let first = 61864918973511u64;
+    let second = (1_23_456, 1234567);
+    let after = 61864918973511u64;
+    let between = 1_23_456;
+    let last = 12345678;
+}
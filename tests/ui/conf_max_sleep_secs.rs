@@ -0,0 +1,11 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_max_sleep_secs.toml"))]
+#![warn(long_sleep_literal)]
+
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    thread::sleep(Duration::from_secs(3600));
+    thread::sleep(Duration::from_secs(86_400));
+}
@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_portable_literals.toml"))]
+#[warn(portable_literal, unreadable_literal)]
+#[allow(unused_variables)]
+fn main() {
+    let good = (1000, 12345678, 0xDEADBEEF, 1234.5678_f32, 0xFF_u8);
+    let bad = (1_000, 1_000_000_u32, 0xDEAD_BEEF, 1_234.567_8);
+}
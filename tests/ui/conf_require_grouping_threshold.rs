@@ -0,0 +1,12 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_require_grouping_threshold.toml"))]
+#![warn(unreadable_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let short = 100;
+    let required = 1000;
+    let grouped = 10_000;
+    let one_group = 0x1234;
+    let long = 0x12345;
+}
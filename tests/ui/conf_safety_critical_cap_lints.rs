@@ -0,0 +1,10 @@
+// compile-flags: --cap-lints warn
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_safety_critical.toml"))]
+#![warn(warnings)]
+#![allow(unused_variables, exceeding_bitshifts, const_err, overflowing_literals)]
+
+fn main() {
+    let shifted = 1u32 << 40;
+    let byte: u8 = 1_000;
+}
@@ -0,0 +1,7 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_show_decimal_value.toml"))]
+#[warn(unreadable_literal, inconsistent_digit_grouping)]
+#[allow(unused_variables)]
+fn main() {
+    let bad = (0xDEADBEEF_u32, 0b1010101010101010_u16, 0xFFF_FF_u32, 12345678);
+}
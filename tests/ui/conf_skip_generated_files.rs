@@ -0,0 +1,10 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_skip_generated_files.toml"))]
+#![warn(unreadable_literal, inconsistent_digit_grouping)]
+
+#[path = "../auxiliary/generated_literals.rs"]
+mod generated_literals;
+
+fn main() {
+    let _ = (generated_literals::TABLE, generated_literals::lookup(0), 61864918973511u64);
+}
@@ -0,0 +1,7 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_terse_suggestions.toml"))]
+#[warn(unreadable_literal, inconsistent_digit_grouping, large_digit_groups)]
+#[allow(unused_variables)]
+fn main() {
+    let literals = (12345678, 1_23_456, 1_23456, 0xDEADBEEF_u32);
+}
@@ -0,0 +1,14 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(date_like_literal)]
+#![allow(unreadable_literal, unused_variables)]
+
+fn main() {
+    let release = 20210101;
+    let regrouped = 20_210_101;
+    let suffixed = 20200229_u32;
+    let grouped = 2021_01_01;
+    let invalid_month = 12345678;
+    let invalid_day = 20210230;
+    let not_leap = 21000229;
+}
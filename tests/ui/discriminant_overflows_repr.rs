@@ -0,0 +1,25 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(discriminant_overflows_repr)]
+#![allow(dead_code, overflowing_literals)]
+
+#[repr(u8)]
+enum Byte {
+    A = 255,
+    B = 300,
+}
+
+#[repr(i8)]
+enum Signed {
+    A = -128,
+    B = -129,
+    C = 126,
+    D = 0x81,
+}
+
+// Without a `#[repr]`, the discriminant is an `isize`.
+enum Plain {
+    A = 300,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(exit_code_literal)]
+#![allow(dead_code)]
+
+use std::process;
+
+const USAGE_ERROR: i32 = 2;
+
+fn succeed() {
+    process::exit(0);
+}
+
+fn fail() {
+    process::exit(1);
+}
+
+fn usage() {
+    process::exit(2);
+}
+
+fn config() {
+    std::process::exit(78);
+}
+
+fn named() {
+    process::exit(USAGE_ERROR);
+}
+
+fn main() {}
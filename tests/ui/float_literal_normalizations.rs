@@ -0,0 +1,12 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(redundant_exponent, uppercase_exponent_marker, padded_float_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    // all three apply to the same literal, which gets a single suggestion
+    let a = 00_1.5000E0;
+    let b = 0100.50E+3_f64;
+    // only one applies
+    let c = 1.5E3;
+}
@@ -0,0 +1,12 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(identifier_like_literal)]
+#![allow(unused_variables, inconsistent_digit_grouping)]
+
+fn main() {
+    let card = 4111_1111_1111_1111_u64;
+    let iban_like = 1234_5678_9012_3456_78_u128;
+    let three_groups = 1234_5678_9012;
+    let by_three = 1_234_567_890_123;
+    let hex = 0x1234_5678_9ABC_DEF0_u64;
+}
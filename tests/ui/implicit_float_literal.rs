@@ -0,0 +1,9 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(implicit_float_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let bad = (5f64, 5_f32, 1_000f64);
+    let good = (5.0f64, 5.0, 5, 1e3f64, 2.5_f32);
+}
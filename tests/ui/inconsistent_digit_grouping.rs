@@ -3,6 +3,9 @@
 #[warn(inconsistent_digit_grouping)]
 #[allow(unused_variables)]
 fn main() {
-    let good = (123, 1_234, 1_2345_6789, 123_f32, 1_234.12_f32, 1_234.123_4_f32, 1.123_456_7_f32);
-    let bad = (1_23_456, 1_234_5678, 1234_567, 1_234.5678_f32, 1.234_5678_f32);
+    let good = (123, 1_234, 1_2345_6789, 123_f32, 1_234.12_f32, 1_234.123_4_f32, 1.123_456_7_f32, 1____, 0.123_456);
+    let bad = (1_23_456, 1_234_5678, 1234_567, 1_234.5678_f32, 1.234_5678_f32, 11_, 12_3, 0.12_345_6, 0.123_45_6_f64);
+    let grouped_by_four = (1234_5678, 12_3456_7890, 1234_5678_u64, 1234_5678.1234_5678_f64, 1_2345.6789_f64);
+    let short = (0, 1, 42, 1_2, 1_, 1., 1e5, 0x1, 7u8);
+    let one_by_one = (1_2_3_4_5, 0xF_F_F_F_F, 1_2_3, 0b1_0_1_0_1, 0o1_7_5_5);
 }
@@ -0,0 +1,13 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(inconsistent_digit_grouping)]
+#![allow(dead_code)]
+
+const MIXED: [u32; 3] = [1_000, 2000, 3_000];
+const GROUPED: [u32; 3] = [1_000, 2_000, 3_000];
+const UNGROUPED: [u32; 3] = [1000, 2000, 3000];
+const SHORT: [u32; 3] = [1_000, 200, 3_000];
+
+fn main() {
+    let _ = [10_000, 2000, 3000, 40_000];
+}
@@ -0,0 +1,12 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![allow(unused_variables)]
+
+fn main() {
+    let release = 2021_01_01;
+}
+
+#[warn(date_like_literal)]
+fn dates() {
+    let release = 2021_01_01;
+}
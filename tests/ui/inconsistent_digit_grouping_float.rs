@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(inconsistent_digit_grouping)]
+#[allow(unused_variables)]
+fn main() {
+    let good = (1_234.567_8, 1234_5678.1234_5678, 1_2345.6789, 1_000., 12.345_678, 12.3456_7890, 1234_5678.123);
+    let bad = (1_234.5678_1234, 12_345.1234_5678, 1_234.5678, 1234_5678.123_4);
+}
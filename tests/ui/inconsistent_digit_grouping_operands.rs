@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(inconsistent_digit_grouping)]
+#[allow(unused_variables, eq_op, identity_op)]
+fn main() {
+    let bad = (1_000 / 1000, 10_000 * 4000, 1_000 + 1000 + 1);
+    let good = (1_000 / 1_000, 1_000_000 / 10, 1000 / 1000, 5 + 1_000, 1_000_000 / 1000, 5000 * 1_000_000);
+}
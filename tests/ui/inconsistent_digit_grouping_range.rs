@@ -0,0 +1,9 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(inconsistent_digit_grouping)]
+#[allow(unused_variables)]
+fn main() {
+    let bad = (1000..1_000_000, 1_000_000..9999);
+    let good = (1_000..1_000_000, 0..10, 5..1_000, 1000..2000, 0xFFFF..0x1_0000);
+    for i in 1000..1_000_000 {}
+}
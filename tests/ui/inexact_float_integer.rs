@@ -0,0 +1,15 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(inexact_float_integer)]
+#![allow(unused_variables, unreadable_literal, cast_precision_loss)]
+
+fn main() {
+    let a: f64 = 9007199254740993.0;
+    let b: f64 = 9007199254740992.0;
+    let c: f64 = 1000.0;
+    let d = 16777217 as f32;
+    let e = 16777217 as f64;
+    let f = 16_777_217_f32;
+    let g: f64 = 9007199254740993.5;
+    let h: f64 = 1e300;
+}
@@ -0,0 +1,15 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(integer_bound_literal)]
+#![allow(unused_variables, unreadable_literal)]
+
+fn main() {
+    let a: i32 = 2147483647;
+    let b: u32 = 4294967295;
+    let c: i64 = -9_223_372_036_854_775_808;
+    let d = 255u8;
+    let e: u8 = 255;
+    let f: i64 = 2147483647;
+    let g: usize = 18446744073709551615;
+    let h: i32 = 2147483646;
+}
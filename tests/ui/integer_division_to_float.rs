@@ -0,0 +1,16 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(integer_division_to_float)]
+#![allow(unused_variables)]
+
+fn main() {
+    let total: u32 = 7;
+    let count = 3i64;
+    let a = (total / 2) as f64;
+    let b = (100 / count) as f32;
+    let c = ((total + 1) / 2) as f64;
+    let d = (total / 2u32) as f64;
+    let e = (total / 2) as u64;
+    let f = (total * 2) as f64;
+    let g = f64::from(total) / 2.0;
+}
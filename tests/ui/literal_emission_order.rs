@@ -0,0 +1,11 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(unreadable_literal, suffix_on_binary_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let flags = 0xA5u32;
+    let tuple = (1234567, 7654321, 1111111);
+    let masked = (flags + 1234567) & 0xFF_u32;
+    let array = [2345678, 8765432];
+}
@@ -0,0 +1,15 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(long_sleep_literal)]
+
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    thread::sleep(Duration::from_secs(3600));
+    thread::sleep(Duration::from_millis(3_600_000));
+    thread::sleep(Duration::from_secs(600));
+    thread::sleep(Duration::from_millis(100));
+    let hour = Duration::from_secs(3600);
+    thread::sleep(hour);
+}
@@ -0,0 +1,9 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(misleading_float_precision)]
+#![allow(unused_variables, unreadable_literal)]
+
+fn main() {
+    let bad = (0.10000000000000001, 1.00000000000000001, 0.1000000001_f32);
+    let good = (0.123456789012345, 0.1, 0.5000, 1., 0.1e-3, 100_000.000_01, 0.1000001_f32);
+}
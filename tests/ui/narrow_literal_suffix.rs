@@ -0,0 +1,15 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(narrow_literal_suffix)]
+#![allow(unused_variables, exceeding_bitshifts, const_err, shift_exceeds_width)]
+
+const X: u64 = (1u32 << 40) as u64;
+const Y: u64 = 1u64 << 40;
+
+fn main() {
+    let a = (100_000_u32 * 100_000) as u64;
+    let b = (100 + 100i8) as i16;
+    let c = (1u32 << 20) as u64;
+    let d = (1 << 40u32) as u64;
+    let e = (0xFFFF_FFFF_u32 * 0xFFFF_FFFF) as u64;
+}
@@ -0,0 +1,11 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(negative_zero_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let bad = -0.0;
+    let bad_suffixed = -0.0_f32;
+    let bad_long = -0.000;
+    let good = (-0.5, 0.0, -1, 0.5_f64);
+}
@@ -0,0 +1,13 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(nonzero_divided_by_zero)]
+#![allow(unused_variables, zero_divided_by_zero, eq_op)]
+
+fn main() {
+    let infinity = 1.0 / 0.0;
+    let f32_infinity = 2.5f32 / 0.0;
+    let neg_infinity = -1.0 / 0.0;
+    let also_neg_infinity = 1.0f64 / -0.0;
+    let nan = 0.0 / 0.0;
+    let half = 1.0 / 2.0;
+}
@@ -0,0 +1,14 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(overflowing_grouped_literal)]
+#![allow(overflowing_literals, unused_variables, integer_bound_literal)]
+
+fn main() {
+    let a: u8 = 1_000;
+    let b: u16 = 1_000;
+    let c: i32 = 3_000_000_000;
+    let d = 70_000_u16;
+    let e: i8 = -128_i8;
+    let f: i8 = -1_28;
+    let g: u8 = 1000;
+}
@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(padded_float_literal)]
+#[allow(unused_variables)]
+fn main() {
+    let good = (0.0, 1.0, 100.5, 0.05, 1., 10e5, 2.5_f32);
+    let bad = (0100.5000, 007.00, 1.50_f32, 00.5e10, 1_0.0_0);
+}
@@ -0,0 +1,14 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(percentage_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let value: u32 = 250;
+    let tax = value * 7 / 100;
+    let share = 15 * value / 100;
+    let half = value / 2;
+    let tenth = value * 7 / 10;
+    let pct = 7;
+    let rate = value * pct / 100;
+}
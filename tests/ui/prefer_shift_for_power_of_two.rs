@@ -0,0 +1,18 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(prefer_shift_for_power_of_two)]
+#![allow(dead_code, unused_assignments, unused_variables, unreadable_literal)]
+
+const A: u32 = 65536;
+const B: u32 = 65535;
+const C: u32 = 4;
+static D: u64 = 1_099_511_627_776;
+const E: u32 = 0x1_0000;
+const F: u32 = 1 << 16;
+
+fn main() {
+    let mut x = 0u64;
+    x = 2048u64;
+    x = 2047;
+    let y = 65536;
+}
@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(redundant_exponent)]
+#![allow(unused_variables)]
+fn main() {
+    let good = (1.0, 1.0e1, 2.5e-3, 1e10, 1.5e10_f64);
+    let bad = (1.0e0, 2.5e0, 1e0, 3.0E+0, 1.5e00_f32);
+}
@@ -0,0 +1,21 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(redundant_operand_suffix)]
+#![allow(unused_variables)]
+
+fn generic<T>(t: T) -> T {
+    t
+}
+
+fn main() {
+    let x: u32 = 5;
+    let y = x + 3u32;
+    let z = 10_u32 * x;
+    let mut w: f64 = 2.0;
+    w -= 1f64;
+    let v = w / 0.5_f64;
+    let inferred = 5;
+    let a = inferred + 3u32;
+    let b = generic(3u32);
+    let c = x + 3;
+}
@@ -0,0 +1,26 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(repeated_time_conversion)]
+
+fn convert(secs: u64, millis: u64) -> (u64, u64) {
+    (secs * 1000, millis / 1_000)
+}
+
+fn convert_once(secs: u64) -> u64 {
+    secs * 1000
+}
+
+fn other_units(secs: u64, millis: u64) -> (u64, u64) {
+    (secs * 60, millis / 1024)
+}
+
+fn thousand_divided_by(secs: u64) -> (u64, u64) {
+    (1000 / secs, 1000 - secs)
+}
+
+fn main() {
+    let timeout = 5;
+    let timeout_ms = timeout * 1000;
+    let timeout_us = timeout_ms * 1000;
+    let _ = (convert(1, 2), convert_once(3), other_units(4, 5), thousand_divided_by(6), timeout_us);
+}
@@ -0,0 +1,16 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(repeating_decimal_literal)]
+#![allow(dead_code, unused_variables, unreadable_literal)]
+
+const THIRD: f64 = 0.3333333333333333;
+const TWO_THIRDS: f64 = 0.6666666666666666;
+const SEVENTH: f32 = 0.142857_f32;
+static SIXTH: f64 = 0.166667;
+const HALF: f64 = 0.5;
+const SHORT: f64 = 0.333;
+const NOT_A_FRACTION: f64 = 0.123456;
+
+fn main() {
+    let third = 0.3333333333333333;
+}
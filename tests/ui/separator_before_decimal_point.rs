@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(separator_before_decimal_point)]
+#[allow(unused_variables)]
+fn main() {
+    let bad = (1000_.5, 1_000_.5, 1_.5_f32, 12_345_.678_9e3, 1_.);
+    let good = (1_000.5, 1000.5, 1.5_f32, 1_000.5e3);
+}
@@ -0,0 +1,15 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(shift_exceeds_width)]
+#![allow(unused_variables, unused_assignments, exceeding_bitshifts, const_err)]
+
+fn main() {
+    let a = 1u32 << 40;
+    let b = 1u32 << 31;
+    let c = 1u64 << 40;
+    let d = 0x80_u8 >> 8;
+
+    let mut e = 1i16;
+    e <<= 16;
+    e >>= 15;
+}
@@ -0,0 +1,15 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(suffix_on_binary_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let flags = 0xA5u8;
+    let low = flags & 0b1010u8;
+    let high = 0xF0_u8 | flags;
+    let toggled = flags ^ 0x0F_u8;
+    let decimal = flags & 10u8;
+    let unsuffixed = flags & 0b1010;
+    let both = 0b1010u8 & 0b0110u8;
+    let shifted = flags << 0b1u8;
+}
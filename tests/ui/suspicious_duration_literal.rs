@@ -0,0 +1,17 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(suspicious_duration_literal)]
+#![allow(unused_variables)]
+
+use std::time::Duration;
+
+fn main() {
+    let a = Duration::from_millis(60);
+    let b = Duration::from_millis(3600);
+    let c = Duration::from_secs(86_400_000);
+
+    let d = Duration::from_secs(60);
+    let e = Duration::from_secs(86_399_999);
+    let f = Duration::from_millis(86_400_000);
+    let g = Duration::from_millis(250);
+}
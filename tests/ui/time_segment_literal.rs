@@ -0,0 +1,13 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(time_segment_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let time = 12_34_56;
+    let timeout = 1_30_00;
+    let too_many = 12_34_56_78;
+    let minutes = 12_64_00;
+    let thousands = 123_456;
+    let hex = 0x12_34_56;
+}
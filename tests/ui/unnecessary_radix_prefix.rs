@@ -0,0 +1,16 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(unnecessary_radix_prefix)]
+#![allow(unused_variables)]
+
+fn main() {
+    let retries = 0x5;
+    let bit = 0b1u8;
+    let mode = 0o7_u32;
+    let ten = 0xA;
+    let padded = 0x05;
+    let zero = 0x0;
+    let decimal = 5;
+    let bytes = [0x0A, 0x5, 0xD];
+    let flags = 7 & 0x4;
+}
@@ -0,0 +1,12 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(unreadable_literal)]
+#![allow(dead_code)]
+
+struct Buffer {
+    data: [u8; 1000000],
+}
+
+fn main() {
+    let _ = Buffer { data: [0; 1000000] };
+}
@@ -0,0 +1,20 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(unreadable_literal)]
+
+// Not in std yet, but a common helper written like this.
+macro_rules! matches {
+    ($e:expr, $p:pat) => {
+        match $e {
+            $p => true,
+            _ => false,
+        }
+    };
+}
+
+fn main() {
+    let x = 1_000_000;
+    assert_eq!(x, 1000000);
+    assert!(x < 10000000, "x is {}", 20000000);
+    assert!(matches!(x, 0...9999999));
+}
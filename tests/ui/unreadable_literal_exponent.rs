@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(unreadable_literal, large_digit_groups)]
+#[allow(unused_variables)]
+fn main() {
+    let good = (1.5e10, 1.5e100, 6.022_140_76e23, 12_345e-3, 1e100, 1.5E+300_f64);
+    let bad = (1.23456789012345e100, 12345.67890e10, 1_23456.5e3_f64, 98765e-12);
+}
@@ -0,0 +1,10 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(unreadable_literal)]
+
+fn main() {
+    println!("{}", 1048576);
+    println!("1048576 {}", 10);
+    let _s = format!("{} {}", 12345678, "x");
+    println!("{:x}", 0xDEADBEEF_u32);
+}
@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(unreadable_literal, large_digit_groups, mixed_case_hex_literals)]
+#[allow(unused_variables)]
+fn main() {
+    let good = (0xABCD_EF12, 0xabcd_ef12);
+    let bad = (0xabcdEF12, 0xabc_DEF12_u32);
+}
@@ -0,0 +1,11 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(unreadable_literal)]
+#[allow(unused_variables)]
+fn main() {
+    let v = vec![0u8; 4];
+    let a = v[1048576];
+    let b = v[1_048_576];
+    let c = v[v[0] as usize + 1048576];
+    let d = [1048576, 0][0];
+}
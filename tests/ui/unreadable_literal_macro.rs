@@ -0,0 +1,13 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(unreadable_literal)]
+
+macro_rules! from_macro {
+    () => {
+        12345678
+    }
+}
+
+fn main() {
+    let _sum = from_macro!() + 87654321;
+}
@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(unreadable_literal)]
+#[allow(unused_variables)]
+fn main() {
+    let good = (1234, 0xFFFF, 0b1111, 0b11111, 0b10101010, 0o7777);
+    let bad = (12345, 0x1FFFF, 0b111111111, 0o77777, 0o17777);
+}
@@ -0,0 +1,44 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(unreadable_literal)]
+#![allow(dead_code)]
+
+macro_rules! twice {
+    ($e:expr) => { ($e, $e) }
+}
+
+mod outer {
+    pub fn first() -> u32 {
+        12345678
+    }
+
+    pub mod inner {
+        pub fn second() -> u32 {
+            fn nested() -> u32 {
+                12345678
+            }
+            nested() + 12345678
+        }
+    }
+}
+
+fn main() {
+    let _pair = twice!(12345678);
+    let _again = twice!(12345678);
+}
+
+// Both functions have the literal argument at the same span. It is an index in
+// the first one only, and still checked in the second one.
+macro_rules! index_and_value {
+    ($e:expr) => {
+        fn index(t: &[u8]) -> u8 {
+            t[$e]
+        }
+
+        fn value() -> usize {
+            $e
+        }
+    }
+}
+
+index_and_value!(12345678);
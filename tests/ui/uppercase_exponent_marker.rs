@@ -0,0 +1,8 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#[warn(uppercase_exponent_marker)]
+#[allow(unused_variables)]
+fn main() {
+    let good = (1e10, 2.5e-3_f32, 0xE, 0xEE_u8, 1.5);
+    let bad = (1E10, 2.5E-3_f32, 6.022E+23);
+}
@@ -0,0 +1,9 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(verbose_zero_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let bad = (0x0, 0b0, 0o0, 0x00, 0x0_u8, 0b0000_0000);
+    let good = (0, 0x00FF, 0b1, 0_u8, 00);
+}
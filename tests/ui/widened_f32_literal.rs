@@ -0,0 +1,11 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![warn(widened_f32_literal)]
+#![allow(unused_variables)]
+
+fn main() {
+    let a = 1.5f32 as f64;
+    let b = 0.1_f32 as f64;
+    let c = 1.5 as f64;
+    let d = 1.5f64 as f32;
+}
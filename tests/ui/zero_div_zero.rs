@@ -1,7 +1,7 @@
 #![feature(plugin)]
 #![plugin(clippy)]
 
-#[allow(unused_variables)]
+#[allow(unused_variables, nonzero_divided_by_zero)]
 #[warn(zero_divided_by_zero)]
 fn main() {
     let nan = 0.0 / 0.0;
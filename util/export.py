@@ -12,7 +12,9 @@ lint_subheadline = re.compile(r'''^\*\*([\w\s]+?)[:?.!]?\*\*(.*)''')
 CONF_TEMPLATE = """\
 This lint has the following configuration variables:
 
-* `%s: %s`: %s (defaults to `%s`)."""
+%s"""
+
+CONF_VAR_TEMPLATE = "* `%s: %s`: %s (defaults to `%s`)."
 
 
 def parse_lint_def(lint):
@@ -52,7 +54,7 @@ def main():
         lints[lint.name] = parse_lint_def(lint)
         if lint.name in configs:
             lints[lint.name]['docs']['Configuration'] = \
-                CONF_TEMPLATE % configs[lint.name]
+                CONF_TEMPLATE % "\n".join(CONF_VAR_TEMPLATE % conf for conf in configs[lint.name])
 
     outfile = sys.argv[1] if len(sys.argv) > 1 else "util/gh-pages/lints.json"
     with open(outfile, "w") as fp:
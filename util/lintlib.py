@@ -14,7 +14,7 @@ lintname_re = re.compile(r'''pub\s+([A-Z_][A-Z_0-9]*)''')
 level_re = re.compile(r'''(Forbid|Deny|Warn|Allow)''')
 conf_re = re.compile(r'''define_Conf! {\n([^}]*)\n}''', re.MULTILINE)
 confvar_re = re.compile(
-    r'''/// Lint: (\w+). (.*).*\n\s*\([^,]+,\s+"([^"]+)",\s+([^=\)]+)=>\s+(.*)\),''', re.MULTILINE)
+    r'''/// Lint: ([\w:*, ]+)\. (.*).*\n\s*\([^,]+,\s+"([^"]+)",\s+([^=\)]+)=>\s+(.*)\),''', re.MULTILINE)
 
 
 def parse_lints(lints, filepath):
@@ -68,7 +68,7 @@ def parse_lints(lints, filepath):
                     comment = True
 
 
-def parse_configs(path):
+def parse_configs(path, lints):
     configs = {}
     with open(os.path.join(path, 'utils/conf.rs')) as fp:
         contents = fp.read()
@@ -76,8 +76,22 @@ def parse_configs(path):
     match = re.search(conf_re, contents)
     confvars = re.findall(confvar_re, match.group(1))
 
-    for (lint, doc, name, default, ty) in confvars:
-        configs[lint.lower()] = Config(name, ty, doc, default)
+    # A configuration variable may affect several lints, and a lint may have
+    # several configuration variables. `module::*` stands for all the lints
+    # declared in `module`.
+    for (lint_names, doc, name, default, ty) in confvars:
+        for lint_name in lint_names.split(','):
+            lint_name = lint_name.strip()
+            if lint_name.endswith('::*'):
+                module = lint_name[:-len('::*')]
+                affected = [lint.name for lint in lints
+                            if os.path.splitext(os.path.basename(lint.sourcefile))[0] == module]
+                if not affected:
+                    log.warn("Warning: no lints in module %s for config %s", module, name)
+            else:
+                affected = [lint_name.lower()]
+            for lint in affected:
+                configs.setdefault(lint, []).append(Config(name, ty, doc, default))
 
     return configs
 
@@ -89,7 +103,7 @@ def parse_all(path="clippy_lints/src"):
             parse_lints(lints, os.path.join(path, filename))
     log.info("got %s lints", len(lints))
 
-    configs = parse_configs(path)
+    configs = parse_configs(path, lints)
     log.info("got %d configs", len(configs))
 
     return lints, configs
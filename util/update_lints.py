@@ -27,6 +27,16 @@ declare_restriction_lint_re = re.compile(r'''
     " (?P<desc>(?:[^"\\]+|\\.)*) " \s* [})]
 ''', re.VERBOSE | re.DOTALL)
 
+register_lint_group_re = re.compile(r'''
+    reg\.register_lint_group\( \s* " (?P<group>[a-z_]+) " \s*,\s*
+    vec!\[ (?P<lints>[^\]]*) \]
+''', re.VERBOSE | re.DOTALL)
+
+lint_metadata_re = re.compile(r'''
+    name: \s* " (?P<name>[a-z_0-9]+) " \s*,\s*
+    version: \s* " (?P<version>[^"]+) "
+''', re.VERBOSE | re.DOTALL)
+
 nl_escape_re = re.compile(r'\\\n\s*')
 
 docs_link = 'https://rust-lang-nursery.github.io/rust-clippy/master/index.html'
@@ -78,6 +88,45 @@ def gen_mods(lints):
         yield 'pub mod %s;\n' % module
 
 
+def gen_lint_metadata(lints, groups, versions, clippy_version):
+    """Write the metadata of lints, in the order they are declared.
+
+    Lints keep the version recorded in `versions`, new lints get the current
+    version of clippy.
+    """
+    yield 'pub static LINT_METADATA: [LintMetadata; %d] = [\n' % len(lints)
+    for (module, name, _, _) in lints:
+        path = '%s::%s' % (module, name.upper())
+        yield '    LintMetadata {\n'
+        yield '        name: "%s",\n' % name
+        yield '        version: "%s",\n' % versions.get(name, clippy_version)
+        yield '        groups: &[%s],\n' % ', '.join(
+            '"%s"' % group for group in sorted(groups)
+            if path in groups[group])
+        yield '    },\n'
+    yield '];\n'
+
+
+def collect_groups(fn):
+    """Collect the lints of each lint group registered in a file, besides
+    `clippy` and `clippy_pedantic`."""
+    with open(fn) as fp:
+        code = fp.read()
+    groups = {}
+    for match in register_lint_group_re.finditer(code):
+        if match.group('group') not in ('clippy', 'clippy_pedantic'):
+            groups[match.group('group')] = set(
+                re.findall(r'[a-z_:]+::[A-Z_0-9]+', match.group('lints')))
+    return groups
+
+
+def collect_versions(fn):
+    """Collect the versions of the lints in a file's generated metadata."""
+    with open(fn) as fp:
+        code = fp.read()
+    return dict(lint_metadata_re.findall(code))
+
+
 def gen_deprecated(lints):
     """Declare deprecated lints"""
 
@@ -218,6 +267,23 @@ def main(print_only=False, check=False):
         r'\]\);', lambda: gen_group(restriction_lints),
         replace_start=False, write_back=not check)
 
+    # same for "clippy_literal_representation" lint collection
+    changed |= replace_region(
+        'clippy_lints/src/lib.rs', r'reg.register_lint_group\("clippy_literal_representation"',
+        r'\]\);', lambda: gen_group([lint for lint in lints if lint[0] == 'literal_digit_grouping']),
+        replace_start=False, write_back=not check)
+
+    # same for the metadata of the `literal_digit_grouping` lints, once the
+    # lint groups are up to date
+    changed |= replace_region(
+        'clippy_lints/src/literal_digit_grouping.rs', r'begin lint metadata', r'end lint metadata',
+        lambda: gen_lint_metadata(
+            [lint for lint in lints if lint[0] == 'literal_digit_grouping'],
+            collect_groups('clippy_lints/src/lib.rs'),
+            collect_versions('clippy_lints/src/literal_digit_grouping.rs'),
+            clippy_version),
+        replace_start=False, write_back=not check)
+
     if check and changed:
         print('Please run util/update_lints.py to regenerate lints lists.')
         return 1